@@ -1,40 +1,268 @@
 import { convertFileSrc, invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
 import { load } from "@tauri-apps/plugin-store";
 import { useCallback, useEffect, useRef, useState } from "react";
 import {
   checkScreenRecordingPermission,
   requestScreenRecordingPermission,
 } from "tauri-plugin-macos-permissions-api";
-import {
-  getMonitorScreenshot,
-  getScreenshotableMonitors,
-} from "tauri-plugin-screenshots-api";
 import { Badge } from "./components/Badge";
+import { type CronSchedule, nextCronTime, parseCron } from "./cron";
 import Settings, {
   DEFAULT_AUTO_ANALYZE,
   DEFAULT_AUTO_CAPTURE_INTERVAL,
+  DEFAULT_CAPTURE_MODE,
+  DEFAULT_CAPTURING_INDICATOR,
   DEFAULT_MODEL,
+  DEFAULT_PRE_CAPTURE_WARNING_SECONDS,
   DEFAULT_PROMPT,
+  DEFAULT_QUIET_HOURS_ENABLED,
+  DEFAULT_QUIET_HOURS_END,
+  DEFAULT_QUIET_HOURS_START,
+  DEFAULT_SCHEDULE_ENABLED,
+  DEFAULT_SCHEDULE_END,
+  DEFAULT_SCHEDULE_START,
+  DEFAULT_TRAY_TITLE_CADENCE,
+  MAX_AUTO_CAPTURE_INTERVAL,
+  MIN_AUTO_CAPTURE_INTERVAL,
+  validateCapturingIndicator,
 } from "./Settings";
 
 type PermissionStatus = "checking" | "granted" | "denied" | "unknown";
 type LocationPermissionStatus = "checking" | "authorized" | "denied" | "notDetermined" | "restricted" | "disabled" | "unknown";
 type Tab = "capture" | "settings";
 
+// 画面収録の権限がない場合の案内
+const SCREEN_PERMISSION_MESSAGE =
+  "画面収録の権限がありません。システム設定の「プライバシーとセキュリティ > 画面収録」で許可してください";
+
+interface AppErrorPayload {
+  category: "capture" | "analysis";
+  message: string;
+  consecutive_failures: number;
+}
+
+// Rust側のCommandError（エラー種別ごとに対処方法を案内する）
+interface CommandError {
+  code:
+    | "auth"
+    | "network"
+    | "rate_limit"
+    | "service"
+    | "filesystem"
+    | "permission"
+    | "invalid_input"
+    | "canceled"
+    | "skipped"
+    | "failure";
+  message: string;
+}
+
+function isCommandError(error: unknown): error is CommandError {
+  return typeof error === "object" && error !== null && "code" in error && "message" in error;
+}
+
+// エラー種別に応じた対処方法を付けたメッセージ
+function commandErrorMessage(error: unknown): string {
+  if (!isCommandError(error)) return String(error);
+  switch (error.code) {
+    case "auth":
+      return `${error.message}（設定タブでAPIキーを確認してください）`;
+    case "network":
+      return `${error.message}（ネットワーク接続を確認してください）`;
+    case "permission":
+      return `${error.message}（キーチェーンへのアクセスを許可してください）`;
+    default:
+      return error.message;
+  }
+}
+
+interface CaptureDiagnostic {
+  monitor_count: number;
+  temp_path: string;
+  file_size: number | null;
+  format: string | null;
+  width: number | null;
+  height: number | null;
+  read_error: string | null;
+  temp_path_accepted: boolean;
+  temp_path_error: string | null;
+}
+
+interface StageTiming {
+  min_ms: number;
+  avg_ms: number;
+  max_ms: number;
+}
+
+interface PipelineBenchmark {
+  iterations: number;
+  sample_width: number;
+  sample_height: number;
+  output_bytes: number;
+  load: StageTiming;
+  resize: StageTiming;
+  encode: StageTiming;
+  total: StageTiming;
+}
+
+interface IntegrityReport {
+  orphaned_sidecars: string[];
+  missing_analyses: string[];
+  corrupt_sidecars: string[];
+  deleted: string[];
+}
+
+interface FocusSession {
+  label: string;
+  started_at: string;
+  ended_at?: string;
+}
+
+// 一時的に短くした撮影間隔（untilはUNIXエポックからのミリ秒）
+interface IntervalBoost {
+  seconds: number;
+  until: number;
+}
+
+// 現在の電源と撮影間隔に掛ける倍率（get_power_sourceコマンド）
+interface PowerState {
+  source: "ac" | "battery" | "unknown";
+  interval_multiplier: number;
+}
+
+// 自動撮影のスヌーズ（untilはUNIXエポックからのミリ秒）
+interface Snooze {
+  until: number;
+}
+
+// セルフタイマーの撮影結果（delayed-capture-firedイベント）
+interface DelayedCaptureFired {
+  paths: string[];
+  error: string | null;
+}
+
+// セルフタイマーの待ち時間（秒）
+const SELF_TIMER_SECONDS = 5;
+
+// ブーストボタンの撮影間隔と継続時間
+const BOOST_SECONDS = 15;
+const BOOST_DURATION_MINUTES = 30;
+// ボタンからスヌーズする時間（分）
+const SNOOZE_MINUTES = 60;
+// 電源（バッテリー駆動か）を確認する間隔（秒）
+const POWER_CHECK_INTERVAL_SECONDS = 60;
+
+interface BatchProgress {
+  current: number;
+  total: number;
+  filename: string;
+  success: boolean;
+}
+
+interface ReoptimizeProgress {
+  current: number;
+  total: number;
+  filename: string;
+  saved_bytes: number;
+}
+
+interface ReoptimizeSummary {
+  total: number;
+  reencoded: number;
+  skipped: number;
+  saved_bytes: number;
+  failed: { filename: string; error: string }[];
+}
+
+// 撮影済み画像を再圧縮する際のJPEG品質（デフォルトと範囲）
+const DEFAULT_REOPTIMIZE_QUALITY = 75;
+const MIN_REOPTIMIZE_QUALITY = 10;
+const MAX_REOPTIMIZE_QUALITY = 95;
+
+interface BatchSummary {
+  total: number;
+  succeeded: number;
+  failed: { filename: string; error: string }[];
+}
+
+// ローカル日付をYYYY-MM-DD形式で返す（日付フォルダ名と同じ形式）
+function formatLocalDate(date: Date): string {
+  const y = date.getFullYear();
+  const m = String(date.getMonth() + 1).padStart(2, "0");
+  const d = String(date.getDate()).padStart(2, "0");
+  return `${y}-${m}-${d}`;
+}
+
+// "HH:MM"形式の時刻を0時からの経過分に変換
+function toMinutes(time: string): number {
+  const [hours, minutes] = time.split(":").map(Number);
+  return hours * 60 + minutes;
+}
+
+// 現在時刻が撮影時間帯内かを判定（日をまたぐ時間帯にも対応）
+function isWithinSchedule(start: string, end: string, now: Date): boolean {
+  const current = now.getHours() * 60 + now.getMinutes();
+  const startMinutes = toMinutes(start);
+  const endMinutes = toMinutes(end);
+  if (startMinutes === endMinutes) return true;
+  if (startMinutes < endMinutes) {
+    return current >= startMinutes && current < endMinutes;
+  }
+  return current >= startMinutes || current < endMinutes;
+}
+
 function App() {
   const [screenshotSrc, setScreenshotSrc] = useState<string | null>(null);
   const [screenshotPath, setScreenshotPath] = useState<string | null>(null);
+  // ひとつ前に撮影したスクリーンショット（差分表示用）
+  const [previousCapturePath, setPreviousCapturePath] = useState<
+    string | null
+  >(null);
+  const lastCapturePathRef = useRef<string | null>(null);
   const [isCapturing, setIsCapturing] = useState(false);
   const [isAnalyzing, setIsAnalyzing] = useState(false);
+  const [currentAnalysisId, setCurrentAnalysisId] = useState<number | null>(null);
   const [permissionStatus, setPermissionStatus] =
     useState<PermissionStatus>("checking");
   const [locationPermissionStatus, setLocationPermissionStatus] =
     useState<LocationPermissionStatus>("checking");
   const [debugInfo, setDebugInfo] = useState<string>("");
   const [analysisResult, setAnalysisResult] = useState<string | null>(null);
+  // 分析は成功したが分析結果JSONを保存できなかった場合のエラー
+  const [sidecarWarning, setSidecarWarning] = useState<string | null>(null);
   const [activeTab, setActiveTab] = useState<Tab>("capture");
   const [hasApiKey, setHasApiKey] = useState(false);
 
+  // 自動撮影・分析の直近のエラー（成功するまで表示）
+  const [autoError, setAutoError] = useState<AppErrorPayload | null>(null);
+
+  // 一括分析用state
+  const [batchDate, setBatchDate] = useState(() => formatLocalDate(new Date()));
+  const [isBatchAnalyzing, setIsBatchAnalyzing] = useState(false);
+  const [reoptimizeQuality, setReoptimizeQuality] = useState(
+    DEFAULT_REOPTIMIZE_QUALITY,
+  );
+  const [reoptimizeProgress, setReoptimizeProgress] =
+    useState<ReoptimizeProgress | null>(null);
+  const [isReoptimizing, setIsReoptimizing] = useState(false);
+  const [batchProgress, setBatchProgress] = useState<BatchProgress | null>(
+    null,
+  );
+
+  const [integrityReport, setIntegrityReport] =
+    useState<IntegrityReport | null>(null);
+
+  // 週報用state（開始日のデフォルトは6日前＝今日までの7日間）
+  const [weeklyStartDate, setWeeklyStartDate] = useState(() =>
+    formatLocalDate(new Date(Date.now() - 6 * 24 * 60 * 60 * 1000)),
+  );
+  const [isGeneratingWeekly, setIsGeneratingWeekly] = useState(false);
+
+  // フォーカスセッション用state
+  const [focusSession, setFocusSession] = useState<FocusSession | null>(null);
+  const [focusLabel, setFocusLabel] = useState("");
+
   // 自動撮影用state
   const [isAutoCapturing, setIsAutoCapturing] = useState(false);
   const [autoCaptureInterval, setAutoCaptureInterval] = useState(
@@ -42,13 +270,77 @@ function App() {
   );
   const [autoAnalyze, setAutoAnalyze] = useState(DEFAULT_AUTO_ANALYZE);
   const [remainingSeconds, setRemainingSeconds] = useState(0);
+  // 撮影前の予告中か（残り秒数が予告秒数以下）
+  const [isPreCaptureWarning, setIsPreCaptureWarning] = useState(false);
+  // 撮影前の予告秒数（Web Workerのtick処理から参照、0で無効）
+  const preCaptureWarningSecondsRef = useRef(
+    DEFAULT_PRE_CAPTURE_WARNING_SECONDS,
+  );
+  // メニューバーの残り時間を更新する間隔（秒）と最後に表示した残り時間
+  // カウントダウンは1秒ごとに進め、表示が変わったときだけトレーを更新する
+  const trayTitleCadenceRef = useRef(DEFAULT_TRAY_TITLE_CADENCE);
+  const lastCountdownTitleRef = useRef<string | null>(null);
+  // 撮影中にメニューバーに表示する文字
+  const capturingIndicatorRef = useRef(DEFAULT_CAPTURING_INDICATOR);
+  // カウントダウンを表示しない時間帯（この間はトレーのタイトルを消す。撮影は続ける）
+  const quietHoursRef = useRef({
+    enabled: DEFAULT_QUIET_HOURS_ENABLED,
+    start: DEFAULT_QUIET_HOURS_START,
+    end: DEFAULT_QUIET_HOURS_END,
+  });
   const [captureCount, setCaptureCount] = useState(0);
   const autoCaptureTimerRef = useRef<number | null>(null);
+  // 撮影タイマーから参照する最新の撮影間隔（撮影中の間隔変更を次回以降に反映）
+  const autoCaptureIntervalRef = useRef(DEFAULT_AUTO_CAPTURE_INTERVAL);
+  // 撮影モード（interval: 一定間隔、cron: cron式で指定した時刻）
+  const [captureMode, setCaptureMode] = useState(DEFAULT_CAPTURE_MODE);
+  const [cronExpression, setCronExpression] = useState("");
+  // 撮影タイマーから参照するcronスケジュール（cronモードでない場合はnull）とcron式
+  const cronScheduleRef = useRef<CronSchedule | null>(null);
+  const cronExpressionRef = useRef("");
+  // 撮影間隔のブースト（期間中はcronモード・通常の撮影間隔より優先）
+  const [intervalBoost, setIntervalBoost] = useState<IntervalBoost | null>(
+    null,
+  );
+  const intervalBoostRef = useRef<IntervalBoost | null>(null);
+  // バッテリー駆動時の撮影間隔の延長（倍率が1より大きい間は通常の撮影間隔を延ばす）
+  const [powerState, setPowerState] = useState<PowerState | null>(null);
+  const powerStateRef = useRef<PowerState | null>(null);
+  const applyPowerStateRef = useRef<((power: PowerState) => void) | null>(
+    null,
+  );
+  // 自動撮影のスヌーズ（期間中は撮影せず、終わると自動で再開する）
+  const [snoozeState, setSnoozeState] = useState<Snooze | null>(null);
+  const snoozeRef = useRef<Snooze | null>(null);
+  const applySnoozeRef = useRef<((snooze: Snooze | null) => void) | null>(
+    null,
+  );
+  // セルフタイマーの撮影待ち
+  const [isDelayedCapturePending, setIsDelayedCapturePending] = useState(false);
+  const handleDelayedCaptureRef = useRef<
+    ((payload: DelayedCaptureFired) => void) | null
+  >(null);
+  const applyIntervalBoostRef = useRef<
+    ((boost: IntervalBoost | null) => void) | null
+  >(null);
   const isStoppingRef = useRef(false);
   const nextCaptureTimeRef = useRef<Date | null>(null);
+  // 次回撮影時刻の表示用（タイマーと同じ値を使い、表示側で再計算しない）
+  const [nextCaptureAt, setNextCaptureAt] = useState<Date | null>(null);
   const isCapturingRef = useRef(false);
   // 最新のtakeScreenshotForAuto関数への参照を保持（setInterval内で使用）
   const takeScreenshotForAutoRef = useRef<(() => Promise<void>) | null>(null);
+  // 撮影時間帯用state
+  const [scheduleEnabled, setScheduleEnabled] = useState(
+    DEFAULT_SCHEDULE_ENABLED,
+  );
+  const [scheduleStart, setScheduleStart] = useState(DEFAULT_SCHEDULE_START);
+  const [scheduleEnd, setScheduleEnd] = useState(DEFAULT_SCHEDULE_END);
+  // 前回チェック時に撮影時間帯内だったか（null: 未チェック）
+  const wasInScheduleRef = useRef<boolean | null>(null);
+  // スーパーバイザーから呼び出すための最新の開始・停止関数への参照
+  const startAutoCaptureRef = useRef<(() => Promise<void>) | null>(null);
+  const stopAutoCaptureRef = useRef<(() => Promise<void>) | null>(null);
   // トレータイトル更新用シーケンス番号（レースコンディション対策）
   const traySeqRef = useRef<number>(0);
   // カウントダウン用Web Worker（バックグラウンドでもスロットリングされない）
@@ -75,20 +367,36 @@ function App() {
     }
   }, []);
 
-  const updateTrayTooltip = useCallback(async (tooltip: string) => {
+  // 自動撮影中かどうかでトレーアイコンを切り替える（カスタムアイコン設定時）
+  const setTrayIconState = useCallback(async (running: boolean) => {
+    try {
+      await invoke("set_tray_icon_state", { running });
+    } catch (error) {
+      console.error("Failed to set tray icon state:", error);
+    }
+  }, []);
+
+  // トレーのツールチップを現在の状態（撮影状態・撮影枚数・最終分析時刻）で更新する
+  const refreshTrayStatus = useCallback(async () => {
     try {
-      await invoke("update_tray_tooltip", { tooltip });
+      await invoke("refresh_tray_status");
     } catch (error) {
-      console.error("Failed to update tray tooltip:", error);
+      console.error("Failed to refresh tray status:", error);
     }
   }, []);
 
   async function checkApiKey() {
     try {
-      const has = await invoke<boolean>("has_vercel_api_key");
+      const has = await invoke<boolean>("has_api_key", { provider: "vercel" });
       setHasApiKey(has);
-    } catch {
+    } catch (error) {
+      // アクセス拒否などは未設定と区別し、理由を表示する
       setHasApiKey(false);
+      const message =
+        typeof error === "object" && error !== null && "message" in error
+          ? String(error.message)
+          : String(error);
+      setDebugInfo(`APIキーを確認できません: ${message}`);
     }
   }
 
@@ -131,12 +439,58 @@ function App() {
       const store = await load("settings.json");
       const savedInterval = await store.get<number>("autoCaptureInterval");
       const savedAutoAnalyze = await store.get<boolean>("autoAnalyze");
+      const savedOnDemandAnalysis = await store.get<boolean>("onDemandAnalysis");
+      const savedPreCaptureWarningSeconds = await store.get<number>(
+        "preCaptureWarningSeconds",
+      );
+      const savedTrayTitleCadence = await store.get<number>("trayTitleCadence");
+      const savedCapturingIndicator =
+        await store.get<string>("capturingIndicator");
+      const savedQuietHoursEnabled =
+        await store.get<boolean>("quietHoursEnabled");
+      const savedQuietHoursStart = await store.get<string>("quietHoursStart");
+      const savedQuietHoursEnd = await store.get<string>("quietHoursEnd");
+      const savedScheduleEnabled = await store.get<boolean>("scheduleEnabled");
+      const savedScheduleStart = await store.get<string>("scheduleStart");
+      const savedScheduleEnd = await store.get<string>("scheduleEnd");
+      const savedCaptureMode = await store.get<string>("captureMode");
+      const savedCronExpression = await store.get<string>("cronExpression");
       if (savedInterval) {
-        setAutoCaptureInterval(savedInterval);
+        changeAutoCaptureInterval(savedInterval);
       }
-      if (savedAutoAnalyze !== undefined) {
+      applyCaptureMode(savedCaptureMode ?? DEFAULT_CAPTURE_MODE, savedCronExpression ?? "");
+      // 分析を後でまとめて行うモードでは、撮影後の自動AI分析を行わない
+      if (savedOnDemandAnalysis) {
+        setAutoAnalyze(false);
+      } else if (savedAutoAnalyze !== undefined) {
         setAutoAnalyze(savedAutoAnalyze);
       }
+      if (savedPreCaptureWarningSeconds !== undefined) {
+        preCaptureWarningSecondsRef.current = savedPreCaptureWarningSeconds;
+      }
+      if (savedTrayTitleCadence !== undefined && savedTrayTitleCadence >= 1) {
+        trayTitleCadenceRef.current = savedTrayTitleCadence;
+      }
+      // 不正な値（手で編集した場合など）はデフォルトの表示に戻す
+      capturingIndicatorRef.current =
+        savedCapturingIndicator !== undefined &&
+        validateCapturingIndicator(savedCapturingIndicator) === null
+          ? savedCapturingIndicator.trim()
+          : DEFAULT_CAPTURING_INDICATOR;
+      quietHoursRef.current = {
+        enabled: savedQuietHoursEnabled ?? DEFAULT_QUIET_HOURS_ENABLED,
+        start: savedQuietHoursStart || DEFAULT_QUIET_HOURS_START,
+        end: savedQuietHoursEnd || DEFAULT_QUIET_HOURS_END,
+      };
+      if (savedScheduleEnabled !== undefined) {
+        setScheduleEnabled(savedScheduleEnabled);
+      }
+      if (savedScheduleStart) {
+        setScheduleStart(savedScheduleStart);
+      }
+      if (savedScheduleEnd) {
+        setScheduleEnd(savedScheduleEnd);
+      }
     } catch (error) {
       console.error("Failed to load auto capture settings:", error);
     }
@@ -147,13 +501,229 @@ function App() {
     checkLocationPermission();
     checkApiKey();
     loadAutoCaptureSettings();
+    invoke<FocusSession | null>("get_focus_session")
+      .then(setFocusSession)
+      .catch((error) => console.error("Failed to get focus session:", error));
+  }, []);
+
+  // 新しいスクリーンショットが保存されたらプレビューを更新
+  useEffect(() => {
+    const unlisten = listen<{ path: string }>("new-capture", (event) => {
+      const savedPath = event.payload.path;
+      setPreviousCapturePath(lastCapturePathRef.current);
+      lastCapturePathRef.current = savedPath;
+      setScreenshotPath(savedPath);
+      // 暗号化した画像（.enc）はファイルを直接表示できないため、Rust側で復号したdata URLを使う
+      if (savedPath.endsWith(".enc")) {
+        invoke<string>("read_capture_image", { imagePath: savedPath })
+          .then(setScreenshotSrc)
+          .catch((error) => setDebugInfo(`暗号化した画像の表示エラー: ${error}`));
+      } else {
+        setScreenshotSrc(`${convertFileSrc(savedPath)}?t=${Date.now()}`);
+      }
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // 自動撮影・分析の失敗を受け取る
+  useEffect(() => {
+    const unlisten = listen<AppErrorPayload>("app-error", (event) => {
+      setAutoError(event.payload);
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // Webhookへの転送失敗を受け取る
+  useEffect(() => {
+    const unlisten = listen<{ path: string; message: string }>(
+      "webhook-error",
+      (event) => {
+        setDebugInfo(`Webhook転送エラー: ${event.payload.message}`);
+      },
+    );
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // 発表・画面共有中のため自動撮影をスキップしたことを受け取る
+  useEffect(() => {
+    const unlisten = listen<{ reason: string }>(
+      "capture-skipped-presenting",
+      (event) => {
+        setDebugInfo(`自動撮影をスキップしました（${event.payload.reason}）`);
+      },
+    );
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // 1日の撮影枚数の上限に達したことを受け取る（日付が変わるまで自動撮影はスキップされる）
+  useEffect(() => {
+    const unlisten = listen<{ limit: number; count: number }>(
+      "daily-cap-reached",
+      (event) => {
+        setDebugInfo(
+          `1日の撮影枚数の上限（${event.payload.limit}枚）に達したため、今日の自動撮影を終了しました`,
+        );
+      },
+    );
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // 自動撮影中にウィンドウへ戻ったら（スリープ復帰など）画面収録の権限を再確認する
+  // 途中で権限が取り消された場合は、次の撮影を待たずに停止して案内する
+  useEffect(() => {
+    if (!isAutoCapturing) return;
+    const recheck = async () => {
+      if (document.visibilityState !== "visible") return;
+      const hasPermission = await checkScreenRecordingPermission().catch(
+        () => true,
+      );
+      if (!hasPermission) {
+        setPermissionStatus("denied");
+        setDebugInfo(`自動撮影を停止しました: ${SCREEN_PERMISSION_MESSAGE}`);
+        stopAutoCaptureRef.current?.();
+      }
+    };
+    window.addEventListener("focus", recheck);
+    document.addEventListener("visibilitychange", recheck);
+    return () => {
+      window.removeEventListener("focus", recheck);
+      document.removeEventListener("visibilitychange", recheck);
+    };
+  }, [isAutoCapturing]);
+
+  // 分析は成功したが分析結果JSONを保存できなかった場合の警告を受け取る
+  useEffect(() => {
+    const unlisten = listen<{ path: string; message: string }>(
+      "analysis-save-failed",
+      (event) => {
+        setSidecarWarning(event.payload.message);
+      },
+    );
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // 開始した分析のIDを受け取る（キャンセル用）
+  useEffect(() => {
+    const unlisten = listen<{ id: number; path: string }>(
+      "analysis-started",
+      (event) => {
+        setCurrentAnalysisId(event.payload.id);
+        setSidecarWarning(null);
+      },
+    );
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // 貼り付けた画像を保存せずにAI分析（分析結果のJSONも保存しない）
+  const analyzePastedImage = useCallback(async (file: File) => {
+    setIsAnalyzing(true);
+    setDebugInfo("貼り付けた画像をAI分析中...");
+    setAnalysisResult(null);
+    try {
+      const dataUrl = await new Promise<string>((resolve, reject) => {
+        const reader = new FileReader();
+        reader.onload = () => resolve(reader.result as string);
+        reader.onerror = () => reject(reader.error);
+        reader.readAsDataURL(file);
+      });
+      setScreenshotPath(null);
+      setScreenshotSrc(dataUrl);
+
+      const store = await load("settings.json");
+      const model = (await store.get<string>("model")) || DEFAULT_MODEL;
+      const prompt = (await store.get<string>("prompt")) || DEFAULT_PROMPT;
+
+      const result = await invoke<string>("analyze_image_base64", {
+        dataUrl,
+        model,
+        prompt,
+      });
+      setAnalysisResult(result);
+      setDebugInfo("分析完了（貼り付けた画像は保存していません）");
+    } catch (error) {
+      setDebugInfo(`AI分析エラー: ${error}`);
+      console.error("Failed to analyze pasted image:", error);
+    } finally {
+      setIsAnalyzing(false);
+    }
+  }, []);
+
+  // 撮影タブで画像を貼り付けたらAI分析する
+  useEffect(() => {
+    if (activeTab !== "capture" || !hasApiKey || isAnalyzing) return;
+    const handlePaste = (event: ClipboardEvent) => {
+      const item = Array.from(event.clipboardData?.items ?? []).find((i) =>
+        i.type.startsWith("image/"),
+      );
+      const file = item?.getAsFile();
+      if (!file) return;
+      event.preventDefault();
+      analyzePastedImage(file);
+    };
+    window.addEventListener("paste", handlePaste);
+    return () => window.removeEventListener("paste", handlePaste);
+  }, [activeTab, hasApiKey, isAnalyzing, analyzePastedImage]);
+
+  // 一括分析の進捗を受け取る
+  useEffect(() => {
+    const unlisten = listen<BatchProgress>("batch-progress", (event) => {
+      setBatchProgress(event.payload);
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // 分析が撮影間隔に追いつかずスキップされたときに、長めの撮影間隔を提案する
+  useEffect(() => {
+    const unlisten = listen<{
+      path: string;
+      average_seconds: number;
+      interval_seconds: number;
+      suggested_interval_seconds: number;
+    }>("analysis-backlog", (event) => {
+      const { average_seconds, suggested_interval_seconds } = event.payload;
+      setDebugInfo(
+        `分析に平均${Math.round(average_seconds)}秒かかっているため、分析をスキップしました（画像は保存済み）。撮影間隔を${suggested_interval_seconds}秒以上にすることをおすすめします`,
+      );
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // 再圧縮の進捗を受け取る
+  useEffect(() => {
+    const unlisten = listen<ReoptimizeProgress>(
+      "reoptimize-progress",
+      (event) => {
+        setReoptimizeProgress(event.payload);
+      },
+    );
+    return () => {
+      unlisten.then((fn) => fn());
+    };
   }, []);
 
   // 自動撮影のクリーンアップ
   useEffect(() => {
     return () => {
       if (autoCaptureTimerRef.current) {
-        clearInterval(autoCaptureTimerRef.current);
+        clearTimeout(autoCaptureTimerRef.current);
       }
       if (timerWorkerRef.current) {
         timerWorkerRef.current.terminate();
@@ -179,44 +749,29 @@ function App() {
         return;
       }
 
-      setDebugInfo("Getting monitors...");
-      const monitors = await getScreenshotableMonitors();
-      if (!monitors || monitors.length === 0) {
-        setDebugInfo(
-          "No monitors found. This typically means screen recording permission is not granted.",
-        );
-        setPermissionStatus("denied");
-        return;
-      }
-      setDebugInfo(`Found ${monitors.length} monitor(s)`);
-
-      const mainMonitor = monitors[0];
-      setDebugInfo(`Taking screenshot of monitor: ${mainMonitor.id}`);
-      const tempPath = await getMonitorScreenshot(mainMonitor.id);
-      setDebugInfo(`Screenshot captured to: ${tempPath}`);
-
-      const savedPath = await invoke<string>("process_screenshot", {
-        sourcePath: tempPath,
-      });
-      setDebugInfo(`Processed and saved to: ${savedPath}`);
-      setScreenshotPath(savedPath);
-
-      const assetUrl = `${convertFileSrc(savedPath)}?t=${Date.now()}`;
-      setScreenshotSrc(assetUrl);
+      // 撮影から保存までをRust側で一括実行
+      setDebugInfo("Capturing...");
+      const savedPaths = await invoke<string[]>("capture_and_process");
+      setDebugInfo(`Processed and saved to: ${savedPaths.join(", ")}`);
 
-      // 自動AI分析が有効かつAPIキーがある場合、分析を実行
+      // 自動AI分析が有効かつAPIキーがある場合、分析を実行（複数ディスプレイの場合は1枚ずつ）
       if (autoAnalyze && hasApiKey) {
         setIsAnalyzing(true);
         setDebugInfo("AI分析中...");
         setAnalysisResult(null);
-        const result = await runAIAnalysis(savedPath);
-        if (result) {
-          setAnalysisResult(result);
-          setDebugInfo("撮影・分析完了");
-        } else {
-          setDebugInfo("撮影完了（分析エラー）");
+        let failed = false;
+        for (const savedPath of savedPaths) {
+          const result = await runAIAnalysis(savedPath);
+          if (result) {
+            setAnalysisResult(result);
+          } else {
+            failed = true;
+          }
         }
+        // 失敗時はrunAIAnalysisが表示したエラー内容を残す
+        if (!failed) setDebugInfo("撮影・分析完了");
         setIsAnalyzing(false);
+        setCurrentAnalysisId(null);
       }
     } catch (error) {
       setDebugInfo(`Error: ${error}`);
@@ -226,23 +781,118 @@ function App() {
     }
   }
 
+  // 1回撮影して、screenshotsプラグインが出力した一時ファイルの情報を表示（保存・分析はしない）
+  async function diagnoseCapture() {
+    setIsCapturing(true);
+    setDebugInfo("撮影を診断中...");
+    try {
+      const d = await invoke<CaptureDiagnostic>("diagnose_capture");
+      const image =
+        d.width !== null && d.height !== null
+          ? `${d.format ?? "不明な形式"} ${d.width}x${d.height}`
+          : `読み込み失敗（${d.read_error}）`;
+      const accepted = d.temp_path_accepted
+        ? "処理対象OK"
+        : `処理対象外（${d.temp_path_error}）`;
+      setDebugInfo(
+        `撮影の診断: モニター${d.monitor_count}台、${d.temp_path}、` +
+          `${d.file_size ?? "?"}バイト、${image}、${accepted}`,
+      );
+    } catch (error) {
+      setDebugInfo(`撮影の診断エラー: ${error}`);
+    } finally {
+      setIsCapturing(false);
+    }
+  }
+
+  // 現在の画像処理の設定で、サンプル画像の読み込み→リサイズ→エンコードの所要時間を計測する
+  async function benchmarkPipeline() {
+    setIsCapturing(true);
+    setDebugInfo("処理時間を計測中...");
+    try {
+      const b = await invoke<PipelineBenchmark>("benchmark_pipeline");
+      const format = (t: StageTiming) =>
+        `平均${Math.round(t.avg_ms)}ms（${Math.round(t.min_ms)}〜${Math.round(t.max_ms)}ms）`;
+      setDebugInfo(
+        `処理時間（${b.sample_width}x${b.sample_height}、${b.iterations}回）: ` +
+          `読み込み${format(b.load)}、リサイズ${format(b.resize)}、` +
+          `エンコード${format(b.encode)}、合計${format(b.total)}、` +
+          `${Math.round(b.output_bytes / 1024)}KB`,
+      );
+    } catch (error) {
+      setDebugInfo(`処理時間の計測エラー: ${error}`);
+    } finally {
+      setIsCapturing(false);
+    }
+  }
+
+  // 画像を保存せずに撮影・AI分析を実行（分析結果のJSONも保存しない）
+  async function analyzeWithoutSaving() {
+    if (!hasApiKey) {
+      setDebugInfo("先にAPIキーを設定してください");
+      setActiveTab("settings");
+      return;
+    }
+
+    setIsCapturing(true);
+    setIsAnalyzing(true);
+    setDebugInfo("保存せずにAI分析中...");
+    setAnalysisResult(null);
+    try {
+      const hasPermission = await checkScreenRecordingPermission();
+      if (!hasPermission) {
+        setDebugInfo(
+          "Screen recording permission denied. Please enable in System Settings.",
+        );
+        setPermissionStatus("denied");
+        return;
+      }
+
+      const store = await load("settings.json");
+      const model = (await store.get<string>("model")) || DEFAULT_MODEL;
+      const prompt = (await store.get<string>("prompt")) || DEFAULT_PROMPT;
+
+      const result = await invoke<string>("analyze_without_saving", {
+        model,
+        prompt,
+      });
+      setAnalysisResult(result);
+      setDebugInfo("分析完了（画像は保存していません）");
+    } catch (error) {
+      setDebugInfo(`AI分析エラー: ${error}`);
+      console.error("Failed to analyze without saving:", error);
+    } finally {
+      setIsCapturing(false);
+      setIsAnalyzing(false);
+    }
+  }
+
   // 指定されたパスでAI分析を実行（UI更新なし、バックグラウンド用）
+  // automated: 自動撮影からの呼び出し（連続失敗時にRust側でapp-errorイベントを送信）
   const runAIAnalysis = useCallback(
-    async (imagePath: string): Promise<string | null> => {
+    async (imagePath: string, automated = false): Promise<string | null> => {
       try {
         const store = await load("settings.json");
         const model = (await store.get<string>("model")) || DEFAULT_MODEL;
         const prompt = (await store.get<string>("prompt")) || DEFAULT_PROMPT;
+        const detail = (await store.get<string>("imageDetail")) || null;
 
         const result = await invoke<string>("analyze_screenshot", {
           imagePath,
           model,
           prompt,
+          detail,
+          automated,
         });
 
         return result;
       } catch (error) {
         console.error("Failed to analyze screenshot:", error);
+        if (isCommandError(error) && error.code === "skipped") {
+          setDebugInfo(`AI分析をスキップ: ${error.message}`);
+        } else {
+          setDebugInfo(`AI分析エラー: ${commandErrorMessage(error)}`);
+        }
         return null;
       }
     },
@@ -251,58 +901,56 @@ function App() {
 
   // 自動撮影用の内部関数（UIのisCapturingを更新しない）
   const takeScreenshotForAuto = useCallback(async () => {
+    // 撮影直前に停止済みでないか最終確認（停止・終了処理と競合した場合は撮影しない）
+    if (isStoppingRef.current) return;
+
     // 撮影中フラグを設定（トレーアイコンの更新用）
     isCapturingRef.current = true;
+    // 撮影後のカウントダウンは表示が同じでもトレーを更新し直す
+    lastCountdownTitleRef.current = null;
 
     try {
       // 停止中でなければ撮影中のステータスをトレーアイコンに表示
       if (!isStoppingRef.current) {
-        await updateTrayTitle("📷");
+        await updateTrayTitle(capturingIndicatorRef.current);
       }
 
       const hasPermission = await checkScreenRecordingPermission();
       if (!hasPermission) {
-        setDebugInfo("自動撮影: 権限がありません。自動撮影を停止します。");
-        stopAutoCapture();
-        return;
-      }
-
-      const monitors = await getScreenshotableMonitors();
-      if (!monitors || monitors.length === 0) {
-        setDebugInfo(
-          "自動撮影: モニターが見つかりません。自動撮影を停止します。",
-        );
+        setPermissionStatus("denied");
+        setDebugInfo(`自動撮影を停止しました: ${SCREEN_PERMISSION_MESSAGE}`);
         stopAutoCapture();
         return;
       }
 
-      const mainMonitor = monitors[0];
-      const tempPath = await getMonitorScreenshot(mainMonitor.id);
-
-      const savedPath = await invoke<string>("process_screenshot", {
-        sourcePath: tempPath,
+      const savedPaths = await invoke<string[]>("capture_and_process", {
+        automated: true,
       });
-
-      setScreenshotPath(savedPath);
-      const assetUrl = `${convertFileSrc(savedPath)}?t=${Date.now()}`;
-      setScreenshotSrc(assetUrl);
+      // 撮影に成功したら撮影エラーの表示を解除
+      setAutoError((prev) => (prev?.category === "capture" ? null : prev));
 
       // 自動AI分析が有効かつAPIキーがある場合、バックグラウンドで分析を実行
-      if (autoAnalyze && hasApiKey) {
-        setDebugInfo(`自動撮影・分析中: ${savedPath}`);
-        // 停止中でなければトレーアイコンを分析中表示に
-        if (!isStoppingRef.current) {
-          await updateTrayTitle("🤖");
-        }
-        const result = await runAIAnalysis(savedPath);
-        if (result) {
-          setAnalysisResult(result);
-          setDebugInfo(`自動撮影・分析完了: ${savedPath}`);
+      // 複数ディスプレイの場合は1枚ずつ分析する
+      for (const savedPath of savedPaths) {
+        if (autoAnalyze && hasApiKey) {
+          setDebugInfo(`自動撮影・分析中: ${savedPath}`);
+          // 停止中でなければトレーアイコンを分析中表示に
+          if (!isStoppingRef.current) {
+            await updateTrayTitle("🤖");
+          }
+          const result = await runAIAnalysis(savedPath, true);
+          if (result) {
+            setAnalysisResult(result);
+            setAutoError((prev) =>
+              prev?.category === "analysis" ? null : prev,
+            );
+            setDebugInfo(`自動撮影・分析完了: ${savedPath}`);
+          } else {
+            setDebugInfo(`自動撮影完了（分析エラー）: ${savedPath}`);
+          }
         } else {
-          setDebugInfo(`自動撮影完了（分析エラー）: ${savedPath}`);
+          setDebugInfo(`自動撮影: ${savedPath}`);
         }
-      } else {
-        setDebugInfo(`自動撮影: ${savedPath}`);
       }
     } catch (error) {
       setDebugInfo(`自動撮影エラー: ${error}`);
@@ -319,37 +967,268 @@ function App() {
     takeScreenshotForAutoRef.current = takeScreenshotForAuto;
   }, [takeScreenshotForAuto]);
 
-  // 自動撮影を開始
-  async function startAutoCapture() {
-    if (isAutoCapturing) return;
+  // 次回撮影時刻を更新する（タイマー用のrefと表示用のstateを同時に更新）
+  function updateNextCaptureTime(time: Date | null) {
+    nextCaptureTimeRef.current = time;
+    setNextCaptureAt(time);
+    syncTimerState();
+  }
 
-    // 停止フラグをリセット
-    isStoppingRef.current = false;
+  // タイマーの状態をRust側に記録する（get_timer_stateでまとめて読み出せるように）
+  function syncTimerState() {
+    const next = nextCaptureTimeRef.current;
+    invoke("sync_timer_state", {
+      running: next !== null,
+      paused: snoozeRef.current !== null,
+      intervalSeconds: cronScheduleRef.current
+        ? null
+        : (intervalBoostRef.current?.seconds ?? currentIntervalSeconds()),
+      nextCaptureAt: next?.getTime() ?? null,
+    }).catch((error) => console.error("Failed to sync timer state:", error));
+  }
 
-    // ツールチップを更新
-    await updateTrayTooltip(`自動撮影中（${autoCaptureInterval}秒間隔）`);
+  // fromより後の次回撮影時刻を求める
+  // cronモードではcron式に一致する次の時刻（一致する時刻がなければnull）、それ以外は撮影間隔後
+  function computeNextCaptureTime(from: Date): Date | null {
+    // スヌーズ中は再開時刻まで撮影しない
+    const snooze = snoozeRef.current;
+    if (snooze && from.getTime() < snooze.until) {
+      return new Date(snooze.until);
+    }
+    const boost = intervalBoostRef.current;
+    if (boost && from.getTime() < boost.until) {
+      return new Date(from.getTime() + boost.seconds * 1000);
+    }
+    if (cronScheduleRef.current) {
+      return nextCronTime(cronScheduleRef.current, from);
+    }
+    return new Date(from.getTime() + currentIntervalSeconds() * 1000);
+  }
 
-    // 最初の撮影を即実行（ref経由で最新の関数を呼び出す）
-    takeScreenshotForAutoRef.current?.();
-    setCaptureCount(1);
+  // 通常の撮影間隔（バッテリー駆動で延長する設定の場合は倍率を掛けた間隔）
+  function currentIntervalSeconds(): number {
+    const multiplier = powerStateRef.current?.interval_multiplier ?? 1;
+    return Math.round(autoCaptureIntervalRef.current * multiplier);
+  }
 
-    // 次回撮影時刻を設定
-    const nextTime = new Date(Date.now() + autoCaptureInterval * 1000);
-    nextCaptureTimeRef.current = nextTime;
-    setRemainingSeconds(autoCaptureInterval);
+  // 撮影モードとcron式を反映する（cron式が不正な場合は一定間隔モードで動かす）
+  // 自動撮影中に変更された場合は、新しいモードで次回撮影をスケジュールし直す
+  function applyCaptureMode(mode: string, expression: string) {
+    let schedule: CronSchedule | null = null;
+    if (mode === "cron") {
+      try {
+        schedule = parseCron(expression);
+      } catch (error) {
+        setDebugInfo(`cron式が不正なため一定間隔で撮影します: ${error}`);
+      }
+    }
+    const changed =
+      (schedule === null) !== (cronScheduleRef.current === null) ||
+      expression !== cronExpressionRef.current;
+    cronScheduleRef.current = schedule;
+    cronExpressionRef.current = expression;
+    setCaptureMode(schedule ? "cron" : "interval");
+    setCronExpression(expression);
 
-    // 撮影タイマーを設定（ref経由で最新の関数を呼び出す）
-    autoCaptureTimerRef.current = window.setInterval(() => {
-      const newNextTime = new Date(Date.now() + autoCaptureInterval * 1000);
-      nextCaptureTimeRef.current = newNextTime;
-      // Web Workerに新しい目標時刻を通知
-      timerWorkerRef.current?.postMessage({
-        type: "updateTarget",
+    if (changed) {
+      rescheduleNextCapture();
+    }
+  }
+
+  // 撮影モード・ブーストの変更を自動撮影中のタイマーに反映する（今から次回撮影時刻を求め直す）
+  function rescheduleNextCapture() {
+    if (!autoCaptureTimerRef.current || isStoppingRef.current) return;
+    const newNextTime = computeNextCaptureTime(new Date());
+    if (!newNextTime) {
+      setDebugInfo("cron式に一致する撮影時刻がないため自動撮影を停止します");
+      stopAutoCaptureRef.current?.();
+      return;
+    }
+    updateNextCaptureTime(newNextTime);
+    timerWorkerRef.current?.postMessage({
+      type: "updateTarget",
+      data: { targetTime: newNextTime.getTime() },
+    });
+    scheduleNextCapture(newNextTime.getTime() - Date.now());
+  }
+
+  // 撮影間隔のブーストを開始・解除する（interval-boostイベントから呼び出す）
+  function applyIntervalBoost(boost: IntervalBoost | null) {
+    intervalBoostRef.current = boost;
+    setIntervalBoost(boost);
+    rescheduleNextCapture();
+    setDebugInfo(
+      boost
+        ? `撮影間隔を${boost.seconds}秒に短縮しました（${new Date(boost.until).toLocaleTimeString("ja-JP")}まで）`
+        : "撮影間隔を元に戻しました",
+    );
+  }
+
+  // 電源の変化を反映する（倍率が変わった場合は、自動撮影中のタイマーをスケジュールし直す）
+  function applyPowerState(power: PowerState) {
+    const previous = powerStateRef.current?.interval_multiplier ?? 1;
+    powerStateRef.current = power;
+    setPowerState(power);
+    if (power.interval_multiplier === previous) return;
+    rescheduleNextCapture();
+    setDebugInfo(
+      power.interval_multiplier > 1
+        ? `バッテリー駆動のため撮影間隔を${power.interval_multiplier}倍に延長しました`
+        : "電源に接続されたため撮影間隔を元に戻しました",
+    );
+  }
+
+  // 自動撮影のスヌーズを開始・解除する（snoozeイベントから呼び出す）
+  function applySnooze(snooze: Snooze | null) {
+    snoozeRef.current = snooze;
+    setSnoozeState(snooze);
+    rescheduleNextCapture();
+    setDebugInfo(
+      snooze
+        ? `自動撮影をスヌーズしました（${new Date(snooze.until).toLocaleTimeString("ja-JP")}に再開）`
+        : "スヌーズを解除しました",
+    );
+  }
+
+  // 次回撮影をdelayMs後にスケジュールする
+  // 撮影のたびに最新の撮影間隔（cronモードではcron式）で次回をスケジュールし直す
+  function scheduleNextCapture(delayMs: number) {
+    if (autoCaptureTimerRef.current) {
+      clearTimeout(autoCaptureTimerRef.current);
+    }
+    autoCaptureTimerRef.current = window.setTimeout(() => {
+      // 停止処理中であれば次回撮影をスケジュールしない
+      if (isStoppingRef.current) return;
+      // ブーストの期間が過ぎていれば元の撮影間隔に戻す
+      const boost = intervalBoostRef.current;
+      if (boost && Date.now() >= boost.until) {
+        intervalBoostRef.current = null;
+        setIntervalBoost(null);
+        setDebugInfo("ブーストの期間が終わったため、撮影間隔を元に戻しました");
+      }
+      // タイマーが予定より早く発火しても同じ時刻に2回撮影しないよう、予定時刻以降から次回を求める
+      const scheduledTime = nextCaptureTimeRef.current?.getTime() ?? 0;
+      // スヌーズの再開時刻になったら解除して撮影を再開する
+      const snooze = snoozeRef.current;
+      if (snooze && Math.max(Date.now(), scheduledTime) >= snooze.until) {
+        snoozeRef.current = null;
+        setSnoozeState(null);
+        setDebugInfo("スヌーズが終わったため自動撮影を再開しました");
+      }
+      const newNextTime = computeNextCaptureTime(
+        new Date(Math.max(Date.now(), scheduledTime)),
+      );
+      takeScreenshotForAutoRef.current?.();
+      setCaptureCount((prev) => prev + 1);
+      if (!newNextTime) {
+        setDebugInfo("cron式に一致する撮影時刻がないため自動撮影を停止します");
+        stopAutoCaptureRef.current?.();
+        return;
+      }
+      updateNextCaptureTime(newNextTime);
+      // Web Workerに新しい目標時刻を通知
+      timerWorkerRef.current?.postMessage({
+        type: "updateTarget",
         data: { targetTime: newNextTime.getTime() },
       });
+      scheduleNextCapture(newNextTime.getTime() - Date.now());
+    }, delayMs);
+  }
+
+  // 撮影間隔を変更する（自動撮影中でも停止せずに反映）
+  // 新しい間隔が次回撮影までの残り時間より短い場合は、次回撮影を前倒しする
+  function changeAutoCaptureInterval(seconds: number) {
+    if (
+      !Number.isFinite(seconds) ||
+      seconds < MIN_AUTO_CAPTURE_INTERVAL ||
+      seconds > MAX_AUTO_CAPTURE_INTERVAL
+    ) {
+      setDebugInfo(
+        `撮影間隔は${MIN_AUTO_CAPTURE_INTERVAL}〜${MAX_AUTO_CAPTURE_INTERVAL}秒で指定してください`,
+      );
+      return;
+    }
+
+    const previous = autoCaptureIntervalRef.current;
+    autoCaptureIntervalRef.current = seconds;
+    setAutoCaptureInterval(seconds);
+    syncTimerState();
+
+    // 自動撮影中でなければ、次回の開始時に反映される
+    // cronモードでは撮影間隔を使わないため、一定間隔モードに戻したときに反映される
+    // スヌーズ中は再開後の撮影から反映される
+    const nextTime = nextCaptureTimeRef.current;
+    if (
+      !autoCaptureTimerRef.current ||
+      !nextTime ||
+      isStoppingRef.current ||
+      cronScheduleRef.current ||
+      snoozeRef.current
+    ) {
+      return;
+    }
+    if (seconds === previous) return;
+
+    const remainingMs = nextTime.getTime() - Date.now();
+    if (remainingMs > seconds * 1000) {
+      const newNextTime = new Date(Date.now() + seconds * 1000);
+      updateNextCaptureTime(newNextTime);
+      timerWorkerRef.current?.postMessage({
+        type: "updateTarget",
+        data: { targetTime: newNextTime.getTime() },
+      });
+      scheduleNextCapture(seconds * 1000);
+    }
+    syncTimerState();
+    setDebugInfo(`撮影間隔を${seconds}秒に変更しました`);
+  }
+
+  // 自動撮影を開始
+  async function startAutoCapture() {
+    if (isAutoCapturing) return;
+
+    // 権限がないと黒い画像しか撮れないため、開始前に確認して開始しない
+    const hasPermission = await checkPermission();
+    if (!hasPermission) {
+      setDebugInfo(`自動撮影を開始できません: ${SCREEN_PERMISSION_MESSAGE}`);
+      return;
+    }
+
+    // 停止フラグをリセット
+    isStoppingRef.current = false;
+    lastCountdownTitleRef.current = null;
+
+    // 停止中に再開時刻を過ぎたスヌーズは解除しておく
+    if (snoozeRef.current && Date.now() >= snoozeRef.current.until) {
+      snoozeRef.current = null;
+      setSnoozeState(null);
+    }
+
+    // 次回撮影時刻を設定（cronモードでは次に一致する時刻）
+    const nextTime = computeNextCaptureTime(new Date());
+    if (!nextTime) {
+      setDebugInfo("cron式に一致する撮影時刻がないため自動撮影を開始できません");
+      return;
+    }
+
+    await setTrayIconState(true);
+
+    // 一定間隔モードでは最初の撮影を即実行（ref経由で最新の関数を呼び出す）
+    // cronモードでは指定した時刻にだけ撮影する（スヌーズ中は再開時刻まで撮影しない）
+    if (cronScheduleRef.current || snoozeRef.current) {
+      setCaptureCount(0);
+    } else {
       takeScreenshotForAutoRef.current?.();
-      setCaptureCount((prev) => prev + 1);
-    }, autoCaptureInterval * 1000);
+      setCaptureCount(1);
+    }
+
+    updateNextCaptureTime(nextTime);
+    setRemainingSeconds(
+      Math.ceil((nextTime.getTime() - Date.now()) / 1000),
+    );
+
+    // 撮影タイマーを設定（ref経由で最新の関数を呼び出す）
+    scheduleNextCapture(nextTime.getTime() - Date.now());
 
     // Web Workerを作成してカウントダウンを開始
     // Web Workerはバックグラウンドでもスロットリングされない
@@ -368,9 +1247,44 @@ function App() {
 
         setRemainingSeconds(remaining);
 
-        // 撮影中でなければ残り時間をトレーアイコンに表示
-        if (!isCapturingRef.current && !isStoppingRef.current) {
-          updateTrayTitle(`${remaining}秒`);
+        // 撮影の数秒前は予告を表示（見られたくないウィンドウを閉じる猶予）
+        const warningSeconds = preCaptureWarningSecondsRef.current;
+        const isWarning =
+          warningSeconds > 0 && remaining > 0 && remaining <= warningSeconds;
+        setIsPreCaptureWarning(isWarning);
+
+        // カウントダウンを表示しない時間帯は、タイトルを一度だけ消して時間帯が終わるまで更新しない
+        // それ以外で撮影中でなければ残り時間をトレーアイコンに表示
+        // cronモードは次回まで長いことがあるため、1分以上は分単位で表示する
+        // 更新間隔が1秒より長い場合は秒数を間隔の倍数に切り上げ、表示が変わったときだけ更新する
+        const quietHours = quietHoursRef.current;
+        const isQuiet =
+          quietHours.enabled &&
+          isWithinSchedule(quietHours.start, quietHours.end, new Date());
+        if (isQuiet && !isStoppingRef.current) {
+          if (lastCountdownTitleRef.current !== "") {
+            lastCountdownTitleRef.current = "";
+            clearTrayTitle();
+          }
+        } else if (!isCapturingRef.current && !isStoppingRef.current) {
+          const cadence = trayTitleCadenceRef.current;
+          const displaySeconds =
+            cadence > 1 ? Math.ceil(remaining / cadence) * cadence : remaining;
+          const remainingText =
+            cronScheduleRef.current && remaining >= 60
+              ? `${Math.ceil(remaining / 60)}分`
+              : `${displaySeconds}秒`;
+          // ブースト中は⚡を付けて表示
+          const boostMark = intervalBoostRef.current ? "⚡" : "";
+          const title = snoozeRef.current
+            ? `スヌーズ中 (残り${Math.ceil(remaining / 60)}分)`
+            : isWarning
+              ? "まもなく撮影"
+              : `${boostMark}${remainingText}`;
+          if (cadence <= 1 || title !== lastCountdownTitleRef.current) {
+            lastCountdownTitleRef.current = title;
+            updateTrayTitle(title);
+          }
         }
       }
     };
@@ -382,7 +1296,11 @@ function App() {
     });
 
     setIsAutoCapturing(true);
-    setDebugInfo(`自動撮影を開始しました（${autoCaptureInterval}秒間隔）`);
+    setDebugInfo(
+      cronScheduleRef.current
+        ? `自動撮影を開始しました（cron: ${cronExpressionRef.current}）`
+        : `自動撮影を開始しました（${autoCaptureInterval}秒間隔）`,
+    );
   }
 
   // 自動撮影を停止
@@ -391,7 +1309,7 @@ function App() {
     isStoppingRef.current = true;
 
     if (autoCaptureTimerRef.current) {
-      clearInterval(autoCaptureTimerRef.current);
+      clearTimeout(autoCaptureTimerRef.current);
       autoCaptureTimerRef.current = null;
     }
     // Web Workerを停止・終了
@@ -401,13 +1319,394 @@ function App() {
       timerWorkerRef.current = null;
     }
     setIsAutoCapturing(false);
-    nextCaptureTimeRef.current = null;
+    updateNextCaptureTime(null);
     setRemainingSeconds(0);
+    setIsPreCaptureWarning(false);
     setDebugInfo("自動撮影を停止しました");
 
     // トレーアイコンをクリア（シーケンス番号により古い更新は無視される）
     await clearTrayTitle();
-    await updateTrayTooltip("ぱしゃログ");
+    await setTrayIconState(false);
+  }
+
+  // スーパーバイザーからは常に最新の開始・停止関数を呼び出す
+  useEffect(() => {
+    startAutoCaptureRef.current = startAutoCapture;
+    stopAutoCaptureRef.current = stopAutoCapture;
+    applyIntervalBoostRef.current = applyIntervalBoost;
+    applyPowerStateRef.current = applyPowerState;
+    applySnoozeRef.current = applySnooze;
+    handleDelayedCaptureRef.current = handleDelayedCapture;
+  });
+
+  // セルフタイマーの撮影結果を受け取る（capture_afterコマンド）
+  useEffect(() => {
+    const unlisten = listen<DelayedCaptureFired>(
+      "delayed-capture-fired",
+      (event) => {
+        handleDelayedCaptureRef.current?.(event.payload);
+      },
+    );
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // 指定秒数後に1回だけ撮影する（セルフタイマー）
+  async function captureAfterDelay() {
+    try {
+      await invoke("capture_after", { delaySeconds: SELF_TIMER_SECONDS });
+      setIsDelayedCapturePending(true);
+      setDebugInfo(`${SELF_TIMER_SECONDS}秒後に撮影します`);
+    } catch (error) {
+      setDebugInfo(`セルフタイマーのエラー: ${error}`);
+    }
+  }
+
+  // セルフタイマーで撮影した画像を、手動撮影と同じく自動AI分析の設定に従って分析する
+  async function handleDelayedCapture(payload: DelayedCaptureFired) {
+    setIsDelayedCapturePending(false);
+    if (payload.error) {
+      setDebugInfo(`セルフタイマーの撮影エラー: ${payload.error}`);
+      return;
+    }
+    setDebugInfo(`セルフタイマーで撮影しました: ${payload.paths.join(", ")}`);
+    if (!autoAnalyze || !hasApiKey || isAnalyzing) return;
+
+    setIsAnalyzing(true);
+    setAnalysisResult(null);
+    for (const savedPath of payload.paths) {
+      const result = await runAIAnalysis(savedPath);
+      if (result) setAnalysisResult(result);
+    }
+    setIsAnalyzing(false);
+    setCurrentAnalysisId(null);
+  }
+
+  // 撮影間隔のブーストの開始・解除を受け取る（boost_interval / cancel_boostコマンド）
+  useEffect(() => {
+    const unlisten = listen<IntervalBoost | null>("interval-boost", (event) => {
+      applyIntervalBoostRef.current?.(event.payload);
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // 電源を定期的に確認し、バッテリー駆動時の撮影間隔の延長に反映する
+  useEffect(() => {
+    const check = async () => {
+      try {
+        const power = await invoke<PowerState>("get_power_source");
+        applyPowerStateRef.current?.(power);
+      } catch (error) {
+        console.error("Failed to get power source:", error);
+      }
+    };
+
+    check();
+    const intervalId = window.setInterval(
+      check,
+      POWER_CHECK_INTERVAL_SECONDS * 1000,
+    );
+    return () => clearInterval(intervalId);
+  }, []);
+
+  // 撮影間隔のブーストを開始・解除する
+  async function toggleIntervalBoost() {
+    try {
+      if (intervalBoost) {
+        await invoke("cancel_boost");
+      } else {
+        await invoke("boost_interval", {
+          seconds: BOOST_SECONDS,
+          durationMinutes: BOOST_DURATION_MINUTES,
+        });
+      }
+    } catch (error) {
+      setDebugInfo(`ブーストの切り替えエラー: ${error}`);
+    }
+  }
+
+  // 自動撮影のスヌーズの開始・解除を受け取る（snooze / cancel_snoozeコマンド）
+  useEffect(() => {
+    const unlisten = listen<Snooze | null>("snooze", (event) => {
+      applySnoozeRef.current?.(event.payload);
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // 自動撮影をスヌーズする・スヌーズを解除する
+  async function toggleSnooze() {
+    try {
+      if (snoozeState) {
+        await invoke("cancel_snooze");
+      } else {
+        await invoke("snooze", { minutes: SNOOZE_MINUTES });
+      }
+    } catch (error) {
+      setDebugInfo(`スヌーズの切り替えエラー: ${error}`);
+    }
+  }
+
+  // メニューからの終了時に自動撮影を停止する
+  useEffect(() => {
+    const unlisten = listen("app-quitting", () => {
+      stopAutoCaptureRef.current?.();
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // 撮影時間帯のスーパーバイザー
+  // 時間帯に入ったら自動撮影を開始し、外れたら停止する（起動時に時間帯内なら即開始）
+  // 切り替わりのタイミングでのみ操作するため、時間帯内の手動停止は尊重される
+  useEffect(() => {
+    if (!scheduleEnabled) {
+      wasInScheduleRef.current = null;
+      return;
+    }
+
+    const check = async () => {
+      const inSchedule = isWithinSchedule(scheduleStart, scheduleEnd, new Date());
+      const wasInSchedule = wasInScheduleRef.current;
+      wasInScheduleRef.current = inSchedule;
+
+      if (inSchedule && wasInSchedule !== true) {
+        await startAutoCaptureRef.current?.();
+      } else if (!inSchedule && wasInSchedule === true) {
+        await stopAutoCaptureRef.current?.();
+        await refreshTrayStatus();
+      } else if (!inSchedule && wasInSchedule === null) {
+        await refreshTrayStatus();
+      }
+    };
+
+    check();
+    const intervalId = window.setInterval(check, 30 * 1000);
+    return () => clearInterval(intervalId);
+  }, [scheduleEnabled, scheduleStart, scheduleEnd, refreshTrayStatus]);
+
+  // 日付フォルダ内の画像をまとめて再分析
+  async function analyzeDateFolder() {
+    setIsBatchAnalyzing(true);
+    setBatchProgress(null);
+    setDebugInfo(`一括分析中: ${batchDate}`);
+    try {
+      const store = await load("settings.json");
+      const model = (await store.get<string>("model")) || DEFAULT_MODEL;
+      const prompt = (await store.get<string>("prompt")) || DEFAULT_PROMPT;
+      const detail = (await store.get<string>("imageDetail")) || null;
+      const concurrency = (await store.get<number>("batchConcurrency")) ?? null;
+
+      const summary = await invoke<BatchSummary>("analyze_date_folder", {
+        date: batchDate,
+        model,
+        prompt,
+        detail,
+        concurrency,
+      });
+      setDebugInfo(
+        `一括分析完了: ${summary.succeeded}/${summary.total}件成功` +
+          (summary.failed.length > 0
+            ? `（失敗: ${summary.failed.map((f) => f.filename).join(", ")}）`
+            : ""),
+      );
+    } catch (error) {
+      setDebugInfo(`一括分析エラー: ${error}`);
+      console.error("Failed to analyze date folder:", error);
+    } finally {
+      setIsBatchAnalyzing(false);
+    }
+  }
+
+  // 日付フォルダの画像と分析結果JSONの対応を確認（deleteOrphans: 孤立したJSONを削除）
+  async function scanIntegrity(deleteOrphans = false) {
+    try {
+      const report = await invoke<IntegrityReport>("scan_integrity", {
+        date: batchDate,
+        deleteOrphans,
+      });
+      setIntegrityReport(report);
+      setDebugInfo(
+        `整合性チェック: 孤立JSON ${report.orphaned_sidecars.length}件` +
+          `（削除 ${report.deleted.length}件）、未分析 ${report.missing_analyses.length}件、` +
+          `壊れたJSON ${report.corrupt_sidecars.length}件`,
+      );
+    } catch (error) {
+      setDebugInfo(`整合性チェックエラー: ${error}`);
+    }
+  }
+
+  // 未分析の画像をまとめて分析（imagePaths未指定の場合は日付フォルダ内の未分析の画像すべて）
+  async function analyzePending(imagePaths: string[] | null = null) {
+    setIsBatchAnalyzing(true);
+    setBatchProgress(null);
+    setDebugInfo(`未分析の画像を分析中: ${batchDate}`);
+    try {
+      const store = await load("settings.json");
+      const model = (await store.get<string>("model")) || DEFAULT_MODEL;
+      const prompt = (await store.get<string>("prompt")) || DEFAULT_PROMPT;
+      const detail = (await store.get<string>("imageDetail")) || null;
+      const concurrency = (await store.get<number>("batchConcurrency")) ?? null;
+
+      const summary = await invoke<BatchSummary>("analyze_pending", {
+        date: batchDate,
+        model,
+        prompt,
+        detail,
+        imagePaths,
+        concurrency,
+      });
+      setDebugInfo(
+        `未分析の画像を分析しました: ${summary.succeeded}/${summary.total}件成功` +
+          (summary.failed.length > 0
+            ? `（失敗: ${summary.failed.map((f) => f.filename).join(", ")}）`
+            : ""),
+      );
+      setIntegrityReport(null);
+    } catch (error) {
+      setDebugInfo(`未分析の画像の分析エラー: ${error}`);
+      console.error("Failed to analyze pending images:", error);
+    } finally {
+      setIsBatchAnalyzing(false);
+    }
+  }
+
+  // 日付フォルダの日報（report.md）に前回以降の分析結果を追記
+  async function updateDailyReport() {
+    try {
+      const update = await invoke<{
+        path: string;
+        appended: number;
+        regenerated: boolean;
+      }>("update_daily_report", { date: batchDate });
+      setDebugInfo(
+        `${update.regenerated ? "日報を作成しました" : "日報を更新しました"}（${update.appended}件追記）: ${update.path}`,
+      );
+    } catch (error) {
+      setDebugInfo(`日報の更新エラー: ${error}`);
+    }
+  }
+
+  // 日付フォルダのJPEGを指定した品質で再圧縮して容量を減らす
+  async function reoptimizeFolder() {
+    setIsReoptimizing(true);
+    setReoptimizeProgress(null);
+    try {
+      const summary = await invoke<ReoptimizeSummary>("reoptimize_folder", {
+        date: batchDate,
+        quality: reoptimizeQuality,
+      });
+      const savedMb = (summary.saved_bytes / 1024 / 1024).toFixed(1);
+      setDebugInfo(
+        `再圧縮しました: ${summary.reencoded}/${summary.total}件（${savedMb}MB削減、スキップ${summary.skipped}件）` +
+          (summary.failed.length > 0
+            ? `（失敗: ${summary.failed.map((f) => f.filename).join(", ")}）`
+            : ""),
+      );
+    } catch (error) {
+      setDebugInfo(`再圧縮エラー: ${error}`);
+    } finally {
+      setIsReoptimizing(false);
+    }
+  }
+
+  // 日付フォルダのスクリーンショットを1枚の一覧画像（contact_sheet.jpg）にまとめてプレビューに表示
+  async function makeContactSheet() {
+    setDebugInfo(`一覧画像を作成中: ${batchDate}`);
+    try {
+      const sheet = await invoke<{
+        path: string;
+        tile_count: number;
+        image_count: number;
+        skipped: number;
+      }>("make_contact_sheet", { date: batchDate });
+      // 一覧画像は分析対象にしない
+      setScreenshotPath(null);
+      setScreenshotSrc(`${convertFileSrc(sheet.path)}?t=${Date.now()}`);
+      setDebugInfo(
+        `一覧画像を保存しました（${sheet.image_count}枚中${sheet.tile_count}枚${sheet.skipped > 0 ? `、読み込めない画像${sheet.skipped}枚` : ""}）: ${sheet.path}`,
+      );
+    } catch (error) {
+      setDebugInfo(`一覧画像の作成エラー: ${error}`);
+    }
+  }
+
+  // ひとつ前のスクリーンショットとの差分画像をプレビューに表示
+  async function showCaptureDiff() {
+    if (!previousCapturePath || !screenshotPath) return;
+    try {
+      const diff = await invoke<{
+        thumbnail: string;
+        changed_percent: number;
+      }>("diff_captures", {
+        pathA: previousCapturePath,
+        pathB: screenshotPath,
+      });
+      // 差分画像は分析対象にしない
+      setScreenshotPath(null);
+      setScreenshotSrc(diff.thumbnail);
+      setDebugInfo(`前回の撮影から${diff.changed_percent}%変化しました`);
+    } catch (error) {
+      setDebugInfo(`差分の作成エラー: ${error}`);
+    }
+  }
+
+  // フォーカスセッションを開始（以降の分析結果にセッション名を記録）
+  async function startFocusSession() {
+    try {
+      const session = await invoke<FocusSession>("start_focus_session", {
+        label: focusLabel,
+      });
+      setFocusSession(session);
+      setFocusLabel("");
+      setDebugInfo(`フォーカスセッションを開始しました: ${session.label}`);
+    } catch (error) {
+      setDebugInfo(`フォーカスセッションの開始エラー: ${error}`);
+    }
+  }
+
+  // フォーカスセッションを終了
+  async function endFocusSession() {
+    try {
+      const session = await invoke<FocusSession | null>("end_focus_session");
+      setFocusSession(null);
+      if (session) {
+        setDebugInfo(`フォーカスセッションを終了しました: ${session.label}`);
+      }
+    } catch (error) {
+      setDebugInfo(`フォーカスセッションの終了エラー: ${error}`);
+    }
+  }
+
+  // 開始日から7日分の分析結果をまとめて週報を作成
+  async function generateWeeklyReport() {
+    setIsGeneratingWeekly(true);
+    setDebugInfo(`週報を作成中: ${weeklyStartDate}から7日間`);
+    try {
+      const store = await load("settings.json");
+      const model = (await store.get<string>("model")) || DEFAULT_MODEL;
+
+      const report = await invoke<{
+        path: string;
+        markdown: string;
+        entry_count: number;
+      }>("generate_weekly_report", {
+        startDate: weeklyStartDate,
+        model,
+      });
+      setAnalysisResult(report.markdown);
+      setDebugInfo(`週報を保存しました（${report.entry_count}件）: ${report.path}`);
+    } catch (error) {
+      setDebugInfo(`週報作成エラー: ${error}`);
+      console.error("Failed to generate weekly report:", error);
+    } finally {
+      setIsGeneratingWeekly(false);
+    }
   }
 
   async function analyzeWithAI() {
@@ -431,14 +1730,36 @@ function App() {
       if (result) {
         setAnalysisResult(result);
         setDebugInfo("分析完了");
-      } else {
-        setDebugInfo("AI分析エラー");
       }
     } catch (error) {
       setDebugInfo(`AI分析エラー: ${error}`);
       console.error("Failed to analyze screenshot:", error);
     } finally {
       setIsAnalyzing(false);
+      setCurrentAnalysisId(null);
+    }
+  }
+
+  // 表示中のスクリーンショットの分析結果JSONを既定のアプリで開く
+  async function openAnalysisJson() {
+    if (!screenshotPath) return;
+    try {
+      await invoke("open_analysis_json", { imagePath: screenshotPath });
+    } catch (error) {
+      setDebugInfo(`JSONを開けませんでした: ${error}`);
+    }
+  }
+
+  // 実行中のAI分析をキャンセル（分析結果のJSONは保存されない）
+  async function cancelAnalysis() {
+    if (currentAnalysisId === null) return;
+    try {
+      const canceled = await invoke<boolean>("cancel_analysis", {
+        id: currentAnalysisId,
+      });
+      setDebugInfo(canceled ? "AI分析をキャンセルしました" : "AI分析はすでに完了しています");
+    } catch (error) {
+      setDebugInfo(`キャンセルエラー: ${error}`);
     }
   }
 
@@ -516,6 +1837,23 @@ function App() {
                             : "位置情報: 不明"}
                 </Badge>
                 {!hasApiKey && <Badge variant="warning">APIキー未設定</Badge>}
+                {autoError && (
+                  <Badge variant="warning">
+                    {autoError.category === "capture"
+                      ? "自動撮影エラー"
+                      : "自動分析エラー"}
+                    （{autoError.consecutive_failures}回連続）
+                  </Badge>
+                )}
+                {permissionStatus === "denied" && (
+                  <button
+                    type="button"
+                    onClick={openScreenRecordingSettings}
+                    className="px-2 py-0.5 text-xs border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 transition-colors"
+                  >
+                    画面収録の設定を開く
+                  </button>
+                )}
               </div>
 
               {/* デバッグ情報 */}
@@ -537,6 +1875,48 @@ function App() {
                 {isCapturing ? "撮影中..." : "スクリーンショットを撮る"}
               </button>
 
+              {/* セルフタイマーボタン */}
+              <button
+                type="button"
+                onClick={captureAfterDelay}
+                disabled={isCapturing || isAutoCapturing || isDelayedCapturePending}
+                className="w-full px-4 py-2.5 text-sm border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 font-medium disabled:opacity-50 disabled:cursor-not-allowed transition-colors"
+              >
+                {isDelayedCapturePending
+                  ? "セルフタイマー撮影待ち..."
+                  : `${SELF_TIMER_SECONDS}秒後に撮影`}
+              </button>
+
+              {/* 保存せずに分析ボタン */}
+              <button
+                type="button"
+                onClick={analyzeWithoutSaving}
+                disabled={isCapturing || isAnalyzing || isAutoCapturing || !hasApiKey}
+                className="w-full px-4 py-2.5 text-sm border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 font-medium disabled:opacity-50 disabled:cursor-not-allowed transition-colors"
+              >
+                保存せずにAI分析
+              </button>
+
+              {/* 撮影の診断ボタン */}
+              <button
+                type="button"
+                onClick={diagnoseCapture}
+                disabled={isCapturing || isAutoCapturing}
+                className="w-full px-4 py-2.5 text-sm border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 font-medium disabled:opacity-50 disabled:cursor-not-allowed transition-colors"
+              >
+                撮影を診断（保存しない）
+              </button>
+
+              {/* 処理時間の計測ボタン */}
+              <button
+                type="button"
+                onClick={benchmarkPipeline}
+                disabled={isCapturing || isAutoCapturing}
+                className="w-full px-4 py-2.5 text-sm border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 font-medium disabled:opacity-50 disabled:cursor-not-allowed transition-colors"
+              >
+                処理時間を計測
+              </button>
+
               {/* 自動撮影コントロール */}
               <div className="p-3 border border-slate-200 rounded-sm bg-white">
                 <div className="flex items-center justify-between mb-2">
@@ -564,11 +1944,61 @@ function App() {
                       <Badge>{captureCount}枚撮影済み</Badge>
                       <span className="text-xs text-slate-500">
                         次回まで {remainingSeconds}秒
+                        {nextCaptureAt &&
+                          `（${nextCaptureAt.toLocaleTimeString("ja-JP")}）`}
                       </span>
+                      {isPreCaptureWarning && (
+                        <Badge variant="warning">まもなく撮影</Badge>
+                      )}
+                      {intervalBoost && (
+                        <Badge variant="warning">
+                          ブースト中（
+                          {new Date(intervalBoost.until).toLocaleTimeString(
+                            "ja-JP",
+                            { hour: "2-digit", minute: "2-digit" },
+                          )}
+                          まで）
+                        </Badge>
+                      )}
+                      {powerState && powerState.interval_multiplier > 1 && (
+                        <Badge variant="warning">
+                          バッテリー駆動のため間隔を延長中
+                        </Badge>
+                      )}
+                      <button
+                        type="button"
+                        onClick={toggleIntervalBoost}
+                        className="px-2 py-0.5 text-xs border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 transition-colors"
+                      >
+                        {intervalBoost
+                          ? "ブースト解除"
+                          : `${BOOST_SECONDS}秒間隔で${BOOST_DURATION_MINUTES}分`}
+                      </button>
+                      {snoozeState && (
+                        <Badge variant="warning">
+                          スヌーズ中（
+                          {new Date(snoozeState.until).toLocaleTimeString(
+                            "ja-JP",
+                            { hour: "2-digit", minute: "2-digit" },
+                          )}
+                          に再開）
+                        </Badge>
+                      )}
+                      <button
+                        type="button"
+                        onClick={toggleSnooze}
+                        className="px-2 py-0.5 text-xs border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 transition-colors"
+                      >
+                        {snoozeState
+                          ? "スヌーズ解除"
+                          : `${SNOOZE_MINUTES / 60}時間スヌーズ`}
+                      </button>
                     </>
                   ) : (
                     <span className="text-xs text-slate-500">
-                      {autoCaptureInterval}秒間隔
+                      {captureMode === "cron"
+                        ? `cron: ${cronExpression}`
+                        : `${autoCaptureInterval}秒間隔`}
                     </span>
                   )}
                 </div>
@@ -590,12 +2020,249 @@ function App() {
                 </button>
               )}
 
+              {/* 前回の撮影との差分 */}
+              {screenshotPath && previousCapturePath && (
+                <button
+                  type="button"
+                  onClick={showCaptureDiff}
+                  className="w-full px-4 py-2.5 text-sm border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 font-medium transition-colors"
+                >
+                  前回との差分を表示
+                </button>
+              )}
+
+              {/* AI分析のキャンセルボタン */}
+              {isAnalyzing && currentAnalysisId !== null && (
+                <button
+                  type="button"
+                  onClick={cancelAnalysis}
+                  className="w-full px-4 py-2.5 text-sm border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 font-medium transition-colors"
+                >
+                  分析をキャンセル
+                </button>
+              )}
+
+              {/* 日付フォルダの一括分析 */}
+              <div className="p-3 border border-slate-200 rounded-sm bg-white">
+                <span className="text-sm font-bold text-slate-700">
+                  一括分析
+                </span>
+                <div className="flex items-center gap-2 mt-2">
+                  <input
+                    type="date"
+                    value={batchDate}
+                    onChange={(e) => setBatchDate(e.target.value)}
+                    className="flex-1 px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+                  />
+                  <button
+                    type="button"
+                    onClick={analyzeDateFolder}
+                    disabled={isBatchAnalyzing || !hasApiKey || !batchDate}
+                    className="px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 font-medium disabled:opacity-50 disabled:cursor-not-allowed transition-colors"
+                  >
+                    {isBatchAnalyzing ? "分析中..." : "再分析"}
+                  </button>
+                  <button
+                    type="button"
+                    onClick={() => analyzePending()}
+                    disabled={isBatchAnalyzing || !hasApiKey || !batchDate}
+                    className="px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 font-medium disabled:opacity-50 disabled:cursor-not-allowed transition-colors"
+                  >
+                    未分析のみ
+                  </button>
+                  <button
+                    type="button"
+                    onClick={updateDailyReport}
+                    disabled={isBatchAnalyzing || !batchDate}
+                    className="px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 font-medium disabled:opacity-50 disabled:cursor-not-allowed transition-colors"
+                  >
+                    日報
+                  </button>
+                  <button
+                    type="button"
+                    onClick={() => scanIntegrity()}
+                    disabled={isBatchAnalyzing || !batchDate}
+                    className="px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 font-medium disabled:opacity-50 disabled:cursor-not-allowed transition-colors"
+                  >
+                    チェック
+                  </button>
+                  <button
+                    type="button"
+                    onClick={makeContactSheet}
+                    disabled={isBatchAnalyzing || !batchDate}
+                    className="px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 font-medium disabled:opacity-50 disabled:cursor-not-allowed transition-colors"
+                  >
+                    一覧画像
+                  </button>
+                </div>
+                {integrityReport &&
+                  (integrityReport.orphaned_sidecars.length > integrityReport.deleted.length ||
+                    integrityReport.missing_analyses.length > 0) && (
+                    <div className="flex items-center gap-2 mt-2">
+                      {integrityReport.orphaned_sidecars.length >
+                        integrityReport.deleted.length && (
+                        <button
+                          type="button"
+                          onClick={() => scanIntegrity(true)}
+                          disabled={isBatchAnalyzing}
+                          className="px-3 py-1.5 text-xs border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 disabled:opacity-50 transition-colors"
+                        >
+                          孤立JSONを削除
+                        </button>
+                      )}
+                      {integrityReport.missing_analyses.length > 0 && (
+                        <button
+                          type="button"
+                          onClick={() => analyzePending(integrityReport.missing_analyses)}
+                          disabled={isBatchAnalyzing || !hasApiKey}
+                          className="px-3 py-1.5 text-xs border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 disabled:opacity-50 transition-colors"
+                        >
+                          未分析の{integrityReport.missing_analyses.length}件を分析
+                        </button>
+                      )}
+                    </div>
+                  )}
+                {isBatchAnalyzing && batchProgress && (
+                  <span className="block mt-2 text-xs text-slate-500 break-all">
+                    {batchProgress.current}/{batchProgress.total}:{" "}
+                    {batchProgress.filename}
+                  </span>
+                )}
+                <div className="flex items-center gap-2 mt-2">
+                  <span className="text-sm text-slate-600">JPEG品質</span>
+                  <input
+                    type="number"
+                    min={MIN_REOPTIMIZE_QUALITY}
+                    max={MAX_REOPTIMIZE_QUALITY}
+                    value={reoptimizeQuality}
+                    onChange={(e) =>
+                      setReoptimizeQuality(
+                        Math.max(
+                          MIN_REOPTIMIZE_QUALITY,
+                          Math.min(
+                            MAX_REOPTIMIZE_QUALITY,
+                            parseInt(e.target.value) ||
+                              DEFAULT_REOPTIMIZE_QUALITY,
+                          ),
+                        ),
+                      )
+                    }
+                    className="w-20 px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+                  />
+                  <span className="text-sm text-slate-600">で</span>
+                  <button
+                    type="button"
+                    onClick={reoptimizeFolder}
+                    disabled={isReoptimizing || isBatchAnalyzing || !batchDate}
+                    className="px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 font-medium disabled:opacity-50 disabled:cursor-not-allowed transition-colors"
+                  >
+                    {isReoptimizing ? "再圧縮中..." : "再圧縮"}
+                  </button>
+                </div>
+                {isReoptimizing && reoptimizeProgress && (
+                  <span className="block mt-2 text-xs text-slate-500 break-all">
+                    {reoptimizeProgress.current}/{reoptimizeProgress.total}:{" "}
+                    {reoptimizeProgress.filename}
+                  </span>
+                )}
+              </div>
+
+              {/* フォーカスセッション */}
+              <div className="p-3 border border-slate-200 rounded-sm bg-white">
+                <span className="text-sm font-bold text-slate-700">
+                  フォーカスセッション
+                </span>
+                {focusSession ? (
+                  <div className="flex items-center gap-2 mt-2">
+                    <span className="flex-1 text-sm text-slate-700 break-all">
+                      {focusSession.label}（
+                      {new Date(focusSession.started_at).toLocaleTimeString(
+                        "ja-JP",
+                        { hour: "2-digit", minute: "2-digit" },
+                      )}
+                      〜）
+                    </span>
+                    <button
+                      type="button"
+                      onClick={endFocusSession}
+                      className="px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 font-medium transition-colors"
+                    >
+                      終了
+                    </button>
+                  </div>
+                ) : (
+                  <div className="flex items-center gap-2 mt-2">
+                    <input
+                      type="text"
+                      value={focusLabel}
+                      onChange={(e) => setFocusLabel(e.target.value)}
+                      placeholder="例: ログイン画面の実装"
+                      className="flex-1 px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+                    />
+                    <button
+                      type="button"
+                      onClick={startFocusSession}
+                      disabled={!focusLabel.trim()}
+                      className="px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 font-medium disabled:opacity-50 disabled:cursor-not-allowed transition-colors"
+                    >
+                      開始
+                    </button>
+                  </div>
+                )}
+                <span className="block mt-2 text-xs text-slate-500">
+                  セッション中の分析結果は日報でタスクごとにまとめられます
+                </span>
+              </div>
+
+              {/* 週報の作成 */}
+              <div className="p-3 border border-slate-200 rounded-sm bg-white">
+                <span className="text-sm font-bold text-slate-700">週報</span>
+                <div className="flex items-center gap-2 mt-2">
+                  <input
+                    type="date"
+                    value={weeklyStartDate}
+                    onChange={(e) => setWeeklyStartDate(e.target.value)}
+                    className="flex-1 px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+                  />
+                  <button
+                    type="button"
+                    onClick={generateWeeklyReport}
+                    disabled={isGeneratingWeekly || !hasApiKey || !weeklyStartDate}
+                    className="px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 font-medium disabled:opacity-50 disabled:cursor-not-allowed transition-colors"
+                  >
+                    {isGeneratingWeekly ? "作成中..." : "作成"}
+                  </button>
+                </div>
+                <span className="block mt-2 text-xs text-slate-500">
+                  開始日から7日間の分析結果を要約します
+                </span>
+              </div>
+
               {/* AI分析結果 */}
               {analysisResult && (
                 <div className="p-3 border border-slate-200 rounded-sm bg-white">
-                  <h3 className="text-sm font-bold text-slate-700 mb-2">
-                    AI分析結果
-                  </h3>
+                  <div className="flex items-center justify-between mb-2">
+                    <h3 className="text-sm font-bold text-slate-700">
+                      AI分析結果
+                    </h3>
+                    {screenshotPath && (
+                      <button
+                        type="button"
+                        onClick={openAnalysisJson}
+                        className="px-2 py-1 text-xs border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 transition-colors"
+                      >
+                        JSONを開く
+                      </button>
+                    )}
+                  </div>
+                  {sidecarWarning && (
+                    <div className="flex items-center gap-2 mb-2">
+                      <Badge variant="warning">JSON未保存</Badge>
+                      <span className="text-xs text-slate-500 break-all">
+                        分析結果JSONを保存できませんでした: {sidecarWarning}
+                      </span>
+                    </div>
+                  )}
                   <p className="text-sm text-slate-600 whitespace-pre-wrap">
                     {analysisResult}
                   </p>
@@ -627,6 +2294,7 @@ function App() {
             <Settings
               onSettingsChange={() => {
                 checkApiKey();
+                loadAutoCaptureSettings();
               }}
             />
           </div>
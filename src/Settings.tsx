@@ -5,14 +5,169 @@ import {
   checkScreenRecordingPermission,
   requestScreenRecordingPermission,
 } from "tauri-plugin-macos-permissions-api";
+import {
+  getScreenshotableMonitors,
+  type ScreenshotableMonitor,
+} from "tauri-plugin-screenshots-api";
 import { Badge } from "./components/Badge";
+import { validateCron } from "./cron";
 
 // Vercel AI Gateway uses provider/model format
 const DEFAULT_MODEL = "google/gemini-2.5-flash-lite";
 const DEFAULT_PROMPT =
   "このスクリーンショットから、今やっている作業を日本語で1〜3行で記録してください。固有名詞（アプリ名、ファイル名、URLなど）は可能な限り残してください。";
 const DEFAULT_AUTO_CAPTURE_INTERVAL = 60; // 秒
+const MIN_AUTO_CAPTURE_INTERVAL = 10; // 秒
+const MAX_AUTO_CAPTURE_INTERVAL = 3600; // 秒
+const DEFAULT_CAPTURE_MODE = "interval"; // 撮影モード（interval: 一定間隔、cron: cron式）
+const DEFAULT_CRON_EXPRESSION = "0,30 * * * *"; // 毎時0分と30分
+const DEFAULT_PRE_CAPTURE_WARNING_SECONDS = 0; // 撮影前の予告（秒、0で無効）
+const MAX_PRE_CAPTURE_WARNING_SECONDS = 30; // 秒
+const DEFAULT_DAILY_CAPTURE_LIMIT = 0; // 1日の撮影枚数の上限（0で無制限）
+const MAX_DAILY_CAPTURE_LIMIT = 10000; // 枚
+const DEFAULT_BATTERY_INTERVAL_MULTIPLIER = 1; // バッテリー駆動時の撮影間隔の倍率（1で延長しない）
+const MAX_BATTERY_INTERVAL_MULTIPLIER = 10; // 倍
+const DEFAULT_TRAY_TITLE_CADENCE = 1; // メニューバーの残り時間を更新する間隔（秒）
+const DEFAULT_CAPTURING_INDICATOR = "📷"; // 撮影中にメニューバーに表示する文字
+const MAX_CAPTURING_INDICATOR_LENGTH = 8; // 文字（絵文字は1文字として数える）
+
+// 撮影中の表示が正しければnull、不正ならエラーメッセージを返す
+function validateCapturingIndicator(indicator: string): string | null {
+  if (indicator.trim() === "") {
+    return "撮影中の表示を入力してください";
+  }
+  if ([...indicator.trim()].length > MAX_CAPTURING_INDICATOR_LENGTH) {
+    return `撮影中の表示は${MAX_CAPTURING_INDICATOR_LENGTH}文字以内にしてください`;
+  }
+  return null;
+}
+
+// メニューバーの残り時間を更新する間隔
+const TRAY_TITLE_CADENCES = [
+  { seconds: 1, name: "1秒ごと" },
+  { seconds: 5, name: "5秒ごと" },
+  { seconds: 10, name: "10秒ごと" },
+  { seconds: 30, name: "30秒ごと" },
+];
 const DEFAULT_AUTO_ANALYZE = false; // 自動AI分析
+const DEFAULT_IDLE_NOTE_ENABLED = false; // 変化が少ない画面の簡易記録
+const DEFAULT_ENCRYPT_AT_REST = false; // 保存する画像の暗号化
+const DEFAULT_ON_DEMAND_ANALYSIS = false; // 撮影後に分析しない（後でまとめて分析）
+const DEFAULT_EMBED_ANALYSIS_COMMENT = false; // 分析に使ったモデル・プロンプトの埋め込み
+const DEFAULT_LIVE_PDF_ENABLED = false; // 撮影ごとのPDF追記
+const DEFAULT_TASK_EXTRACTION_ENABLED = false; // タスク・決定事項の抽出
+const DEFAULT_KEEP_SOURCE_FILES = false; // 撮影時の一時ファイル
+const DEFAULT_PROGRESSIVE_JPEG = false; // プログレッシブJPEG
+const DEFAULT_EMBED_IMAGE_DESCRIPTION = false; // 画像への説明の埋め込み
+const DEFAULT_KEEP_RUNNING_IN_BACKGROUND = true; // バックグラウンドでの実行継続
+const DEFAULT_HEIC_OUTPUT = false; // HEIC形式での保存
+const DEFAULT_CONTEXT_SNAPSHOT_ENABLED = false; // 分析しなかった撮影のコンテキスト記録
+const DEFAULT_LIVE_REPORT_ENABLED = false; // 分析ごとの日報更新
+const DEFAULT_PAUSE_WHILE_PRESENTING = false; // 発表・画面共有中の撮影スキップ
+const DEFAULT_KEEP_FULL_RESOLUTION = false; // 元の解像度のまま保存（Retina）
+const DEFAULT_RESIZE_MAX_WIDTH = 1920; // リサイズ後の最大幅（px）
+const DEFAULT_RESIZE_FILTER = "lanczos3"; // リサイズフィルター
+const DEFAULT_SHARPEN_ENABLED = false; // リサイズ後のシャープ化
+const DEFAULT_SHARPEN_SIGMA = 0.8;
+const DEFAULT_SHARPEN_THRESHOLD = 2;
+const DEFAULT_JPEG_TARGET_SIZE_ENABLED = false; // 目標ファイルサイズに合わせてJPEG品質を自動調整
+const DEFAULT_JPEG_TARGET_SIZE_KB = 300;
+const DEFAULT_KEEP_ORIGINALS = false; // 縮小前の元画像をoriginals/に残す
+const DEFAULT_PRUNE_ORIGINALS_DAYS = 30; // 元画像を削除する際の保持日数
+const DEFAULT_CAPTURE_DISPLAY = "main"; // 撮影するディスプレイ（"main", "all", モニターID）
+const DEFAULT_SUBFOLDER_SCHEME = "date"; // 日付フォルダ内のサブフォルダの分け方
+
+// 日付フォルダ内のサブフォルダの分け方
+const SUBFOLDER_SCHEMES = [
+  { id: "date", name: "分けない（YYYY-MM-DD/）" },
+  { id: "hour", name: "時間ごと（YYYY-MM-DD/HH/）" },
+  { id: "session", name: "フォーカスセッションごと（YYYY-MM-DD/セッション名/）" },
+];
+const DEFAULT_SEQUENCE_PADDING = 3; // ファイル名の連番の桁数
+
+// ファイル名の連番の桁数（同じ秒に撮影できる上限は桁数に収まる最大値）
+const SEQUENCE_PADDINGS = [
+  { width: 3, name: "3桁（_001〜_999）" },
+  { width: 4, name: "4桁（_0001〜_9999）" },
+  { width: 5, name: "5桁（_00001〜_99999）" },
+  { width: 6, name: "6桁（_000001〜_999999）" },
+];
+
+// リサイズフィルター（上ほど高品質、下ほど高速）
+const RESIZE_FILTERS = [
+  { id: "lanczos3", name: "Lanczos3（高品質・デフォルト）" },
+  { id: "catmullRom", name: "CatmullRom" },
+  { id: "gaussian", name: "Gaussian" },
+  { id: "triangle", name: "Triangle（高速）" },
+  { id: "nearest", name: "Nearest（最速・低品質）" },
+];
+const DEFAULT_LOCATION_PRECISION = "full"; // 分析結果・プロンプトに含める位置情報の精度
+
+// 位置情報の精度
+const LOCATION_PRECISIONS = [
+  { id: "full", name: "そのまま記録" },
+  { id: "city", name: "市区町村程度に丸める（約1km）" },
+  { id: "off", name: "記録しない" },
+];
+const DEFAULT_IMAGE_DETAIL = "auto"; // AI分析時の画像解像度指定（OpenAIのdetail）
+const DEFAULT_MAX_IMAGE_SIZE_MB = 0; // 送信する画像の上限（0はプロバイダーごとのデフォルト）
+
+// 画像解像度指定
+const IMAGE_DETAILS = [
+  { id: "auto", name: "自動（モデルに任せる）" },
+  { id: "low", name: "低解像度（安価・文字中心の画面向け）" },
+  { id: "high", name: "高解像度（細かいダッシュボード等向け）" },
+];
+const DEFAULT_BATCH_CONCURRENCY = 1; // 一括分析の同時実行数
+
+// 一括分析の同時実行数（Rust側の上限MAX_BATCH_CONCURRENCYと合わせる）
+const BATCH_CONCURRENCIES = [
+  { count: 1, name: "1件ずつ（順番に分析）" },
+  { count: 2, name: "2件ずつ" },
+  { count: 3, name: "3件ずつ" },
+  { count: 4, name: "4件ずつ（最大）" },
+];
+const DEFAULT_OUTPUT_LANGUAGE = "auto"; // AIの回答言語
+
+// AIの回答言語
+const OUTPUT_LANGUAGES = [
+  { id: "auto", name: "システムの言語に合わせる" },
+  { id: "ja", name: "日本語" },
+  { id: "en", name: "英語" },
+  { id: "zh", name: "中国語" },
+  { id: "ko", name: "韓国語" },
+  { id: "off", name: "指定しない（プロンプトに任せる）" },
+];
+const DEFAULT_OCR_MODE = "off"; // 端末内のOCRの使い方
+
+// 端末内のOCRの使い方
+const OCR_MODES = [
+  { id: "off", name: "使わない" },
+  { id: "withImage", name: "読み取ったテキストを画像と一緒に送信" },
+  { id: "textOnly", name: "テキストのみ送信（画像を送らずトークンを節約）" },
+];
+const DEFAULT_TRAY_LEFT_CLICK = "window"; // トレーアイコンの左クリック動作
+
+// トレーアイコンの左クリック動作
+const TRAY_LEFT_CLICK_BEHAVIORS = [
+  { id: "window", name: "ウィンドウを表示" },
+  { id: "menu", name: "メニューを開く" },
+];
+const DEFAULT_DISPLAY_DATE_FORMAT = "ja"; // 分析結果・レポートの日時表示形式
+
+// 日時表示形式（ファイル名・フォルダ名は常にYYYYMMDD_HHMMSS形式）
+const DISPLAY_DATE_FORMATS = [
+  { id: "ja", name: "2025年1月2日 15:04" },
+  { id: "iso", name: "2025-01-02 15:04:05" },
+  { id: "us", name: "01/02/2025 3:04 PM" },
+  { id: "eu", name: "02.01.2025 15:04" },
+];
+const DEFAULT_SCHEDULE_ENABLED = false; // 撮影時間帯による自動開始・停止
+const DEFAULT_SCHEDULE_START = "09:00";
+const DEFAULT_SCHEDULE_END = "18:00";
+const DEFAULT_QUIET_HOURS_ENABLED = false; // メニューバーのカウントダウンを表示しない時間帯
+const DEFAULT_QUIET_HOURS_START = "22:00";
+const DEFAULT_QUIET_HOURS_END = "07:00";
 
 // Vercel AI Gateway supported models (provider/model format)
 const AVAILABLE_MODELS = [
@@ -23,6 +178,39 @@ const AVAILABLE_MODELS = [
 ];
 
 type PermissionStatus = "checking" | "granted" | "denied" | "unknown";
+
+// Keychainエラー（Rust側のKeychainError）
+interface KeychainError {
+  code: "access_denied" | "unavailable" | "not_found" | "failure";
+  message: string;
+}
+
+function isKeychainError(error: unknown): error is KeychainError {
+  return typeof error === "object" && error !== null && "code" in error && "message" in error;
+}
+
+function keychainErrorMessage(error: unknown): string {
+  return isKeychainError(error) ? error.message : String(error);
+}
+
+// 撮影範囲（スクリーンショットの実ピクセル座標）
+interface CaptureRegion {
+  x: number;
+  y: number;
+  width: number;
+  height: number;
+}
+
+const DEFAULT_CAPTURE_REGION: CaptureRegion = { x: 0, y: 0, width: 1280, height: 720 };
+// コンテキスト情報（Rust側のContextInfo）
+interface ContextInfo {
+  wifi_ssid: string | null;
+  location: { latitude: number; longitude: number } | null;
+  cursor?: { x: number; y: number; display: number };
+}
+
+type TrayIconVariant = "idle" | "running";
+
 type LocationPermissionStatus = "checking" | "authorized" | "denied" | "notDetermined" | "restricted" | "disabled" | "unknown";
 
 interface SettingsProps {
@@ -32,24 +220,145 @@ interface SettingsProps {
 function Settings({ onSettingsChange }: SettingsProps) {
   const [apiKey, setApiKey] = useState("");
   const [hasApiKey, setHasApiKey] = useState(false);
+  // Keychainへのアクセスが拒否された場合のエラー（未設定とは区別して案内する）
+  const [keychainError, setKeychainError] = useState<KeychainError | null>(null);
   const [model, setModel] = useState(DEFAULT_MODEL);
   const [prompt, setPrompt] = useState(DEFAULT_PROMPT);
+  const [promptPrefix, setPromptPrefix] = useState("");
+  const [promptSuffix, setPromptSuffix] = useState("");
+  const [promptPreview, setPromptPreview] = useState<string | null>(null);
   const [autoCaptureInterval, setAutoCaptureInterval] = useState(DEFAULT_AUTO_CAPTURE_INTERVAL);
+  const [captureMode, setCaptureMode] = useState(DEFAULT_CAPTURE_MODE);
+  const [cronExpression, setCronExpression] = useState(DEFAULT_CRON_EXPRESSION);
+  const [preCaptureWarningSeconds, setPreCaptureWarningSeconds] = useState(DEFAULT_PRE_CAPTURE_WARNING_SECONDS);
+  const [dailyCaptureLimit, setDailyCaptureLimit] = useState(DEFAULT_DAILY_CAPTURE_LIMIT);
+  const [batteryIntervalMultiplier, setBatteryIntervalMultiplier] = useState(
+    DEFAULT_BATTERY_INTERVAL_MULTIPLIER,
+  );
+  const [trayTitleCadence, setTrayTitleCadence] = useState(DEFAULT_TRAY_TITLE_CADENCE);
+  const [capturingIndicator, setCapturingIndicator] = useState(DEFAULT_CAPTURING_INDICATOR);
   const [autoAnalyze, setAutoAnalyze] = useState(DEFAULT_AUTO_ANALYZE);
+  const [idleNoteEnabled, setIdleNoteEnabled] = useState(DEFAULT_IDLE_NOTE_ENABLED);
+  const [encryptAtRest, setEncryptAtRest] = useState(DEFAULT_ENCRYPT_AT_REST);
+  const [onDemandAnalysis, setOnDemandAnalysis] = useState(DEFAULT_ON_DEMAND_ANALYSIS);
+  const [embedAnalysisComment, setEmbedAnalysisComment] = useState(DEFAULT_EMBED_ANALYSIS_COMMENT);
+  const [livePdfEnabled, setLivePdfEnabled] = useState(DEFAULT_LIVE_PDF_ENABLED);
+  const [taskExtractionEnabled, setTaskExtractionEnabled] = useState(DEFAULT_TASK_EXTRACTION_ENABLED);
+  const [keepSourceFiles, setKeepSourceFiles] = useState(DEFAULT_KEEP_SOURCE_FILES);
+  const [progressiveJpeg, setProgressiveJpeg] = useState(DEFAULT_PROGRESSIVE_JPEG);
+  const [embedImageDescription, setEmbedImageDescription] = useState(DEFAULT_EMBED_IMAGE_DESCRIPTION);
+  const [keepRunningInBackground, setKeepRunningInBackground] = useState(DEFAULT_KEEP_RUNNING_IN_BACKGROUND);
+  const [heicOutput, setHeicOutput] = useState(DEFAULT_HEIC_OUTPUT);
+  const [subfolderScheme, setSubfolderScheme] = useState(DEFAULT_SUBFOLDER_SCHEME);
+  const [sequencePadding, setSequencePadding] = useState(DEFAULT_SEQUENCE_PADDING);
+  const [contextSnapshotEnabled, setContextSnapshotEnabled] = useState(DEFAULT_CONTEXT_SNAPSHOT_ENABLED);
+  const [liveReportEnabled, setLiveReportEnabled] = useState(DEFAULT_LIVE_REPORT_ENABLED);
+  const [pauseWhilePresenting, setPauseWhilePresenting] = useState(DEFAULT_PAUSE_WHILE_PRESENTING);
+  const [keepFullResolution, setKeepFullResolution] = useState(DEFAULT_KEEP_FULL_RESOLUTION);
+  const [resizeMaxWidth, setResizeMaxWidth] = useState(DEFAULT_RESIZE_MAX_WIDTH);
+  const [resizeFilter, setResizeFilter] = useState(DEFAULT_RESIZE_FILTER);
+  const [sharpenEnabled, setSharpenEnabled] = useState(DEFAULT_SHARPEN_ENABLED);
+  const [sharpenSigma, setSharpenSigma] = useState(DEFAULT_SHARPEN_SIGMA);
+  const [sharpenThreshold, setSharpenThreshold] = useState(DEFAULT_SHARPEN_THRESHOLD);
+  const [jpegTargetSizeEnabled, setJpegTargetSizeEnabled] = useState(DEFAULT_JPEG_TARGET_SIZE_ENABLED);
+  const [jpegTargetSizeKb, setJpegTargetSizeKb] = useState(DEFAULT_JPEG_TARGET_SIZE_KB);
+  const [keepOriginals, setKeepOriginals] = useState(DEFAULT_KEEP_ORIGINALS);
+  const [pruneOriginalsDays, setPruneOriginalsDays] = useState(DEFAULT_PRUNE_ORIGINALS_DAYS);
+  const [isPruningOriginals, setIsPruningOriginals] = useState(false);
+  const [captureDisplay, setCaptureDisplay] = useState(DEFAULT_CAPTURE_DISPLAY);
+  const [displayDateFormat, setDisplayDateFormat] = useState(DEFAULT_DISPLAY_DATE_FORMAT);
+  const [imageDetail, setImageDetail] = useState(DEFAULT_IMAGE_DETAIL);
+  const [batchConcurrency, setBatchConcurrency] = useState(DEFAULT_BATCH_CONCURRENCY);
+  const [outputLanguage, setOutputLanguage] = useState(DEFAULT_OUTPUT_LANGUAGE);
+  const [ocrMode, setOcrMode] = useState(DEFAULT_OCR_MODE);
+  const [trayLeftClick, setTrayLeftClick] = useState(DEFAULT_TRAY_LEFT_CLICK);
+  const [maxImageSizeMb, setMaxImageSizeMb] = useState(DEFAULT_MAX_IMAGE_SIZE_MB);
+  const [forwardWebhookUrl, setForwardWebhookUrl] = useState("");
+  const [webhookSecret, setWebhookSecret] = useState("");
+  const [hasWebhookSecret, setHasWebhookSecret] = useState(false);
+  const [imageUploadEndpoint, setImageUploadEndpoint] = useState("");
+  const [imageUploadBucket, setImageUploadBucket] = useState("");
+  const [imageUploadRegion, setImageUploadRegion] = useState("");
+  const [objectStoreAccessKeyId, setObjectStoreAccessKeyId] = useState("");
+  const [objectStoreSecretKey, setObjectStoreSecretKey] = useState("");
+  const [hasObjectStoreCredentials, setHasObjectStoreCredentials] = useState(false);
+  const [monitors, setMonitors] = useState<ScreenshotableMonitor[]>([]);
+  const [captureRegionEnabled, setCaptureRegionEnabled] = useState(false);
+  const [captureRegion, setCaptureRegion] = useState<CaptureRegion>(DEFAULT_CAPTURE_REGION);
+  const [scheduleEnabled, setScheduleEnabled] = useState(DEFAULT_SCHEDULE_ENABLED);
+  const [quietHoursEnabled, setQuietHoursEnabled] = useState(DEFAULT_QUIET_HOURS_ENABLED);
+  const [quietHoursStart, setQuietHoursStart] = useState(DEFAULT_QUIET_HOURS_START);
+  const [quietHoursEnd, setQuietHoursEnd] = useState(DEFAULT_QUIET_HOURS_END);
+  const [scheduleStart, setScheduleStart] = useState(DEFAULT_SCHEDULE_START);
+  const [scheduleEnd, setScheduleEnd] = useState(DEFAULT_SCHEDULE_END);
   const [isSaving, setIsSaving] = useState(false);
+  const [isTesting, setIsTesting] = useState(false);
+  const [gatewayModels, setGatewayModels] = useState<string[]>([]);
+  const [isLoadingModels, setIsLoadingModels] = useState(false);
   const [message, setMessage] = useState<{
     type: "success" | "error";
     text: string;
   } | null>(null);
   const [permissionStatus, setPermissionStatus] = useState<PermissionStatus>("checking");
   const [locationPermissionStatus, setLocationPermissionStatus] = useState<LocationPermissionStatus>("checking");
+  const [contextInfo, setContextInfo] = useState<ContextInfo | null>(null);
+  const [trayIcons, setTrayIcons] = useState<Record<TrayIconVariant, string | null>>({
+    idle: null,
+    running: null,
+  });
+  const [locationPrecision, setLocationPrecision] = useState(DEFAULT_LOCATION_PRECISION);
 
   useEffect(() => {
     loadSettings();
     checkPermission();
     checkLocationPermission();
+    loadMonitors();
+    loadTrayIcons();
   }, []);
 
+  // カスタムトレーアイコンを読み込む
+  async function loadTrayIcons() {
+    try {
+      const [idle, running] = await Promise.all([
+        invoke<string | null>("get_tray_icon", { variant: "idle" }),
+        invoke<string | null>("get_tray_icon", { variant: "running" }),
+      ]);
+      setTrayIcons({ idle, running });
+    } catch (e) {
+      console.error("Failed to load tray icons:", e);
+    }
+  }
+
+  // 選択した画像をカスタムトレーアイコンに設定（fileがnullの場合はデフォルトに戻す）
+  async function handleTrayIconChange(variant: TrayIconVariant, file: File | null) {
+    try {
+      let imageBase64: string | null = null;
+      if (file) {
+        const dataUrl = await new Promise<string>((resolve, reject) => {
+          const reader = new FileReader();
+          reader.onload = () => resolve(reader.result as string);
+          reader.onerror = () => reject(reader.error);
+          reader.readAsDataURL(file);
+        });
+        imageBase64 = dataUrl.slice(dataUrl.indexOf(",") + 1);
+      }
+      await invoke("set_tray_icon", { variant, imageBase64 });
+      setTrayIcons((prev) => ({ ...prev, [variant]: imageBase64 }));
+      setMessage({ type: "success", text: "トレーアイコンを更新しました" });
+    } catch (error) {
+      setMessage({ type: "error", text: `トレーアイコンの更新に失敗しました: ${error}` });
+    }
+  }
+
+  // 撮影可能なディスプレイの一覧を取得
+  async function loadMonitors() {
+    try {
+      setMonitors(await getScreenshotableMonitors());
+    } catch (e) {
+      console.error("Failed to load monitors:", e);
+    }
+  }
+
   async function checkPermission(): Promise<boolean> {
     setPermissionStatus("checking");
     try {
@@ -89,6 +398,16 @@ function Settings({ onSettingsChange }: SettingsProps) {
     }
   }
 
+  // 現在取得できるWiFi・位置情報を確認
+  async function handleTestContextInfo() {
+    try {
+      setContextInfo(await invoke<ContextInfo>("get_context_info"));
+    } catch (e) {
+      console.error("Failed to get context info:", e);
+    }
+    checkLocationPermission();
+  }
+
   async function handleRequestLocationPermission() {
     try {
       await invoke("request_location_permission");
@@ -111,21 +430,154 @@ function Settings({ onSettingsChange }: SettingsProps) {
 
   async function loadSettings() {
     try {
-      // APIキーの存在確認
-      const hasKey = await invoke<boolean>("has_vercel_api_key");
-      setHasApiKey(hasKey);
+      // APIキーの存在確認（アクセス拒否でも他の設定は読み込む）
+      try {
+        const hasKey = await invoke<boolean>("has_api_key", { provider: "vercel" });
+        setHasApiKey(hasKey);
+        setKeychainError(null);
+        if (hasKey) loadGatewayModels(false);
+      } catch (error) {
+        setKeychainError(
+          isKeychainError(error) ? error : { code: "failure", message: String(error) },
+        );
+      }
+
+      try {
+        setHasWebhookSecret(await invoke<boolean>("has_api_key", { provider: "webhook" }));
+      } catch (error) {
+        console.error("Failed to check webhook secret:", error);
+      }
+
+      try {
+        setHasObjectStoreCredentials(
+          await invoke<boolean>("has_api_key", { provider: "objectstore" }),
+        );
+      } catch (error) {
+        console.error("Failed to check object store credentials:", error);
+      }
 
       // Storeから設定を読み込み
       const store = await load("settings.json");
       const savedModel = await store.get<string>("model");
       const savedPrompt = await store.get<string>("prompt");
+      const savedPromptPrefix = await store.get<string>("promptPrefix");
+      const savedPromptSuffix = await store.get<string>("promptSuffix");
       const savedInterval = await store.get<number>("autoCaptureInterval");
+      const savedCaptureMode = await store.get<string>("captureMode");
+      const savedCronExpression = await store.get<string>("cronExpression");
+      const savedPreCaptureWarningSeconds = await store.get<number>("preCaptureWarningSeconds");
+      const savedDailyCaptureLimit = await store.get<number>("dailyCaptureLimit");
+      const savedBatteryIntervalMultiplier = await store.get<number>("batteryIntervalMultiplier");
+      const savedTrayTitleCadence = await store.get<number>("trayTitleCadence");
+      const savedCapturingIndicator = await store.get<string>("capturingIndicator");
       const savedAutoAnalyze = await store.get<boolean>("autoAnalyze");
+      const savedIdleNoteEnabled = await store.get<boolean>("idleNoteEnabled");
+      const savedEncryptAtRest = await store.get<boolean>("encryptAtRest");
+      const savedOnDemandAnalysis = await store.get<boolean>("onDemandAnalysis");
+      const savedEmbedAnalysisComment = await store.get<boolean>("embedAnalysisComment");
+      const savedLivePdfEnabled = await store.get<boolean>("livePdfEnabled");
+      const savedTaskExtractionEnabled = await store.get<boolean>("taskExtractionEnabled");
+      const savedKeepSourceFiles = await store.get<boolean>("keepSourceFiles");
+      const savedProgressiveJpeg = await store.get<boolean>("progressiveJpeg");
+      const savedEmbedImageDescription = await store.get<boolean>("embedImageDescription");
+      const savedKeepRunningInBackground = await store.get<boolean>("keepRunningInBackground");
+      const savedHeicOutput = await store.get<boolean>("heicOutput");
+      const savedSubfolderScheme = await store.get<string>("subfolderScheme");
+      const savedSequencePadding = await store.get<number>("sequencePadding");
+      const savedContextSnapshotEnabled = await store.get<boolean>("contextSnapshotEnabled");
+      const savedLiveReportEnabled = await store.get<boolean>("liveReportEnabled");
+      const savedPauseWhilePresenting = await store.get<boolean>("pauseWhilePresenting");
+      const savedKeepFullResolution = await store.get<boolean>("keepFullResolution");
+      const savedResizeMaxWidth = await store.get<number>("resizeMaxWidth");
+      const savedResizeFilter = await store.get<string>("resizeFilter");
+      const savedSharpenEnabled = await store.get<boolean>("sharpenEnabled");
+      const savedSharpenSigma = await store.get<number>("sharpenSigma");
+      const savedSharpenThreshold = await store.get<number>("sharpenThreshold");
+      const savedJpegTargetSizeEnabled = await store.get<boolean>("jpegTargetSizeEnabled");
+      const savedJpegTargetSizeKb = await store.get<number>("jpegTargetSizeKb");
+      const savedKeepOriginals = await store.get<boolean>("keepOriginals");
+      const savedCaptureDisplay = await store.get<string>("captureDisplay");
+      const savedDisplayDateFormat = await store.get<string>("displayDateFormat");
+      const savedImageDetail = await store.get<string>("imageDetail");
+      const savedBatchConcurrency = await store.get<number>("batchConcurrency");
+      const savedOutputLanguage = await store.get<string>("outputLanguage");
+      const savedOcrMode = await store.get<string>("ocrMode");
+      const savedTrayLeftClick = await store.get<string>("trayLeftClick");
+      const savedMaxImageSizeMb = await store.get<number>("maxImageSizeMb");
+      const savedLocationPrecision = await store.get<string>("locationPrecision");
+      const savedForwardWebhookUrl = await store.get<string>("forwardWebhookUrl");
+      const savedImageUploadEndpoint = await store.get<string>("imageUploadEndpoint");
+      const savedImageUploadBucket = await store.get<string>("imageUploadBucket");
+      const savedImageUploadRegion = await store.get<string>("imageUploadRegion");
+      const savedCaptureRegion = await store.get<CaptureRegion>("captureRegion");
+      const savedScheduleEnabled = await store.get<boolean>("scheduleEnabled");
+      const savedQuietHoursEnabled = await store.get<boolean>("quietHoursEnabled");
+      const savedQuietHoursStart = await store.get<string>("quietHoursStart");
+      const savedQuietHoursEnd = await store.get<string>("quietHoursEnd");
+      const savedScheduleStart = await store.get<string>("scheduleStart");
+      const savedScheduleEnd = await store.get<string>("scheduleEnd");
 
       if (savedModel) setModel(savedModel);
       if (savedPrompt) setPrompt(savedPrompt);
+      if (savedPromptPrefix !== undefined) setPromptPrefix(savedPromptPrefix);
+      if (savedPromptSuffix !== undefined) setPromptSuffix(savedPromptSuffix);
       if (savedInterval) setAutoCaptureInterval(savedInterval);
+      if (savedCaptureMode) setCaptureMode(savedCaptureMode);
+      if (savedCronExpression) setCronExpression(savedCronExpression);
+      if (savedPreCaptureWarningSeconds !== undefined) setPreCaptureWarningSeconds(savedPreCaptureWarningSeconds);
+      if (savedDailyCaptureLimit !== undefined) setDailyCaptureLimit(savedDailyCaptureLimit);
+      if (savedBatteryIntervalMultiplier !== undefined) {
+        setBatteryIntervalMultiplier(savedBatteryIntervalMultiplier);
+      }
+      if (savedTrayTitleCadence !== undefined) setTrayTitleCadence(savedTrayTitleCadence);
+      if (savedCapturingIndicator) setCapturingIndicator(savedCapturingIndicator);
       if (savedAutoAnalyze !== undefined) setAutoAnalyze(savedAutoAnalyze);
+      if (savedIdleNoteEnabled !== undefined) setIdleNoteEnabled(savedIdleNoteEnabled);
+      if (savedEncryptAtRest !== undefined) setEncryptAtRest(savedEncryptAtRest);
+      if (savedOnDemandAnalysis !== undefined) setOnDemandAnalysis(savedOnDemandAnalysis);
+      if (savedEmbedAnalysisComment !== undefined) setEmbedAnalysisComment(savedEmbedAnalysisComment);
+      if (savedLivePdfEnabled !== undefined) setLivePdfEnabled(savedLivePdfEnabled);
+      if (savedTaskExtractionEnabled !== undefined) setTaskExtractionEnabled(savedTaskExtractionEnabled);
+      if (savedKeepSourceFiles !== undefined) setKeepSourceFiles(savedKeepSourceFiles);
+      if (savedProgressiveJpeg !== undefined) setProgressiveJpeg(savedProgressiveJpeg);
+      if (savedEmbedImageDescription !== undefined) setEmbedImageDescription(savedEmbedImageDescription);
+      if (savedKeepRunningInBackground !== undefined) setKeepRunningInBackground(savedKeepRunningInBackground);
+      if (savedHeicOutput !== undefined) setHeicOutput(savedHeicOutput);
+      if (savedSubfolderScheme) setSubfolderScheme(savedSubfolderScheme);
+      if (savedSequencePadding !== undefined) setSequencePadding(savedSequencePadding);
+      if (savedContextSnapshotEnabled !== undefined) setContextSnapshotEnabled(savedContextSnapshotEnabled);
+      if (savedLiveReportEnabled !== undefined) setLiveReportEnabled(savedLiveReportEnabled);
+      if (savedPauseWhilePresenting !== undefined) setPauseWhilePresenting(savedPauseWhilePresenting);
+      if (savedKeepFullResolution !== undefined) setKeepFullResolution(savedKeepFullResolution);
+      if (savedResizeMaxWidth) setResizeMaxWidth(savedResizeMaxWidth);
+      if (savedResizeFilter) setResizeFilter(savedResizeFilter);
+      if (savedSharpenEnabled !== undefined) setSharpenEnabled(savedSharpenEnabled);
+      if (savedSharpenSigma) setSharpenSigma(savedSharpenSigma);
+      if (savedSharpenThreshold !== undefined) setSharpenThreshold(savedSharpenThreshold);
+      if (savedJpegTargetSizeEnabled !== undefined) setJpegTargetSizeEnabled(savedJpegTargetSizeEnabled);
+      if (savedJpegTargetSizeKb) setJpegTargetSizeKb(savedJpegTargetSizeKb);
+      if (savedKeepOriginals !== undefined) setKeepOriginals(savedKeepOriginals);
+      if (savedCaptureDisplay) setCaptureDisplay(savedCaptureDisplay);
+      if (savedDisplayDateFormat) setDisplayDateFormat(savedDisplayDateFormat);
+      if (savedImageDetail) setImageDetail(savedImageDetail);
+      if (savedBatchConcurrency !== undefined) setBatchConcurrency(savedBatchConcurrency);
+      if (savedOutputLanguage) setOutputLanguage(savedOutputLanguage);
+      if (savedOcrMode) setOcrMode(savedOcrMode);
+      if (savedTrayLeftClick) setTrayLeftClick(savedTrayLeftClick);
+      if (savedMaxImageSizeMb !== undefined) setMaxImageSizeMb(savedMaxImageSizeMb);
+      if (savedLocationPrecision) setLocationPrecision(savedLocationPrecision);
+      if (savedForwardWebhookUrl !== undefined) setForwardWebhookUrl(savedForwardWebhookUrl);
+      if (savedImageUploadEndpoint !== undefined) setImageUploadEndpoint(savedImageUploadEndpoint);
+      if (savedImageUploadBucket !== undefined) setImageUploadBucket(savedImageUploadBucket);
+      if (savedImageUploadRegion !== undefined) setImageUploadRegion(savedImageUploadRegion);
+      setCaptureRegionEnabled(!!savedCaptureRegion);
+      if (savedCaptureRegion) setCaptureRegion(savedCaptureRegion);
+      if (savedScheduleEnabled !== undefined) setScheduleEnabled(savedScheduleEnabled);
+      if (savedQuietHoursEnabled !== undefined) setQuietHoursEnabled(savedQuietHoursEnabled);
+      if (savedQuietHoursStart) setQuietHoursStart(savedQuietHoursStart);
+      if (savedQuietHoursEnd) setQuietHoursEnd(savedQuietHoursEnd);
+      if (savedScheduleStart) setScheduleStart(savedScheduleStart);
+      if (savedScheduleEnd) setScheduleEnd(savedScheduleEnd);
     } catch (error) {
       console.error("Failed to load settings:", error);
     }
@@ -139,13 +591,17 @@ function Settings({ onSettingsChange }: SettingsProps) {
 
     setIsSaving(true);
     try {
-      await invoke("set_vercel_api_key", { apiKey: apiKey.trim() });
+      await invoke("set_api_key", {
+        provider: "vercel",
+        apiKey: apiKey.trim(),
+      });
       setHasApiKey(true);
+      setKeychainError(null);
       setApiKey("");
       setMessage({ type: "success", text: "APIキーを保存しました" });
       onSettingsChange?.();
     } catch (error) {
-      setMessage({ type: "error", text: `保存に失敗しました: ${error}` });
+      setMessage({ type: "error", text: `保存に失敗しました: ${keychainErrorMessage(error)}` });
     } finally {
       setIsSaving(false);
     }
@@ -154,26 +610,180 @@ function Settings({ onSettingsChange }: SettingsProps) {
   async function handleDeleteApiKey() {
     setIsSaving(true);
     try {
-      await invoke("delete_vercel_api_key");
+      await invoke("delete_api_key", { provider: "vercel" });
       setHasApiKey(false);
       setMessage({ type: "success", text: "APIキーを削除しました" });
       onSettingsChange?.();
     } catch (error) {
-      setMessage({ type: "error", text: `削除に失敗しました: ${error}` });
+      setMessage({ type: "error", text: `削除に失敗しました: ${keychainErrorMessage(error)}` });
     } finally {
       setIsSaving(false);
     }
   }
 
+  // 実際に送信されるプロンプト（ヘッダー・コンテキスト情報・フッター付き）を表示
+  async function handlePreviewPrompt() {
+    try {
+      const preview = await invoke<string>("preview_prompt", { prompt });
+      setPromptPreview(preview);
+    } catch (error) {
+      setMessage({ type: "error", text: `プレビューに失敗しました: ${error}` });
+    }
+  }
+
+  // ゲートウェイで利用できるモデル一覧を取得（refresh: falseならキャッシュを使う）
+  async function loadGatewayModels(refresh: boolean) {
+    setIsLoadingModels(true);
+    try {
+      const models = await invoke<string[]>("list_models", { refresh });
+      setGatewayModels(models);
+      if (refresh) {
+        setMessage({ type: "success", text: `モデル一覧を取得しました（${models.length}件）` });
+      }
+    } catch (error) {
+      console.error("Failed to list models:", error);
+      if (refresh) {
+        setMessage({
+          type: "error",
+          text: `モデル一覧の取得に失敗しました: ${keychainErrorMessage(error)}`,
+        });
+      }
+    } finally {
+      setIsLoadingModels(false);
+    }
+  }
+
+  // 同梱のサンプル画像でAPIキー・モデルの動作を確認
+  async function handleTestAnalysis() {
+    setIsTesting(true);
+    setMessage(null);
+    try {
+      const result = await invoke<string>("test_analysis", { model });
+      setMessage({ type: "success", text: `接続テスト成功: ${result}` });
+    } catch (error) {
+      setMessage({ type: "error", text: `接続テストに失敗しました: ${error}` });
+    } finally {
+      setIsTesting(false);
+    }
+  }
+
+  // 指定日数より古い元画像（originals/）を削除
+  async function handlePruneOriginals() {
+    setIsPruningOriginals(true);
+    setMessage(null);
+    try {
+      const summary = await invoke<{ deleted_files: number; freed_bytes: number }>(
+        "prune_originals",
+        { olderThanDays: pruneOriginalsDays },
+      );
+      const freedMb = (summary.freed_bytes / 1024 / 1024).toFixed(1);
+      setMessage({
+        type: "success",
+        text: `元画像を${summary.deleted_files}件削除しました（${freedMb}MB）`,
+      });
+    } catch (error) {
+      setMessage({ type: "error", text: `元画像の削除に失敗しました: ${error}` });
+    } finally {
+      setIsPruningOriginals(false);
+    }
+  }
+
   async function handleSaveSettings() {
+    if (captureMode === "cron" && cronError) {
+      setMessage({ type: "error", text: `cron式が不正です: ${cronError}` });
+      return;
+    }
+    if (capturingIndicatorError) {
+      setMessage({ type: "error", text: capturingIndicatorError });
+      return;
+    }
+    // 前後の空白・プロバイダーの間違いなどを保存前に確認し、正規化したモデルIDで保存する
+    let normalizedModel: string;
+    try {
+      normalizedModel = await invoke<string>("validate_model", { model });
+    } catch (error) {
+      setMessage({ type: "error", text: `モデルが不正です: ${keychainErrorMessage(error)}` });
+      return;
+    }
+    setModel(normalizedModel);
     setIsSaving(true);
     try {
       const store = await load("settings.json");
-      await store.set("model", model);
+      await store.set("model", normalizedModel);
       await store.set("prompt", prompt);
+      await store.set("promptPrefix", promptPrefix);
+      await store.set("promptSuffix", promptSuffix);
       await store.set("autoCaptureInterval", autoCaptureInterval);
+      await store.set("captureMode", captureMode);
+      await store.set("cronExpression", cronExpression.trim());
+      await store.set("preCaptureWarningSeconds", preCaptureWarningSeconds);
+      await store.set("dailyCaptureLimit", dailyCaptureLimit);
+      await store.set("batteryIntervalMultiplier", batteryIntervalMultiplier);
+      await store.set("trayTitleCadence", trayTitleCadence);
+      await store.set("capturingIndicator", capturingIndicator.trim());
       await store.set("autoAnalyze", autoAnalyze);
+      await store.set("idleNoteEnabled", idleNoteEnabled);
+      await store.set("encryptAtRest", encryptAtRest);
+      await store.set("onDemandAnalysis", onDemandAnalysis);
+      await store.set("embedAnalysisComment", embedAnalysisComment);
+      await store.set("livePdfEnabled", livePdfEnabled);
+      await store.set("taskExtractionEnabled", taskExtractionEnabled);
+      await store.set("keepSourceFiles", keepSourceFiles);
+      await store.set("progressiveJpeg", progressiveJpeg);
+      await store.set("embedImageDescription", embedImageDescription);
+      await store.set("keepRunningInBackground", keepRunningInBackground);
+      await store.set("heicOutput", heicOutput);
+      await store.set("subfolderScheme", subfolderScheme);
+      await store.set("sequencePadding", sequencePadding);
+      await store.set("contextSnapshotEnabled", contextSnapshotEnabled);
+      await store.set("liveReportEnabled", liveReportEnabled);
+      await store.set("pauseWhilePresenting", pauseWhilePresenting);
+      await store.set("keepFullResolution", keepFullResolution);
+      await store.set("resizeMaxWidth", resizeMaxWidth);
+      await store.set("resizeFilter", resizeFilter);
+      await store.set("sharpenEnabled", sharpenEnabled);
+      await store.set("sharpenSigma", sharpenSigma);
+      await store.set("sharpenThreshold", sharpenThreshold);
+      await store.set("jpegTargetSizeEnabled", jpegTargetSizeEnabled);
+      await store.set("jpegTargetSizeKb", jpegTargetSizeKb);
+      await store.set("keepOriginals", keepOriginals);
+      await store.set("captureDisplay", captureDisplay);
+      await store.set("displayDateFormat", displayDateFormat);
+      await store.set("imageDetail", imageDetail);
+      await store.set("batchConcurrency", batchConcurrency);
+      await store.set("outputLanguage", outputLanguage);
+      await store.set("ocrMode", ocrMode);
+      await store.set("maxImageSizeMb", maxImageSizeMb);
+      await store.set("locationPrecision", locationPrecision);
+      await store.set("forwardWebhookUrl", forwardWebhookUrl.trim());
+      await store.set("imageUploadEndpoint", imageUploadEndpoint.trim());
+      await store.set("imageUploadBucket", imageUploadBucket.trim());
+      await store.set("imageUploadRegion", imageUploadRegion.trim());
+      await store.set("scheduleEnabled", scheduleEnabled);
+      await store.set("quietHoursEnabled", quietHoursEnabled);
+      await store.set("quietHoursStart", quietHoursStart);
+      await store.set("quietHoursEnd", quietHoursEnd);
+      await store.set("scheduleStart", scheduleStart);
+      await store.set("scheduleEnd", scheduleEnd);
       await store.save();
+      await invoke("set_capture_region", {
+        region: captureRegionEnabled ? captureRegion : null,
+      });
+      await invoke("set_tray_click_behavior", { behavior: trayLeftClick });
+      if (webhookSecret.trim()) {
+        await invoke("set_api_key", { provider: "webhook", apiKey: webhookSecret.trim() });
+        setWebhookSecret("");
+        setHasWebhookSecret(true);
+      }
+      if (objectStoreAccessKeyId.trim() && objectStoreSecretKey.trim()) {
+        await invoke("set_api_key", {
+          provider: "objectstore",
+          apiKey: `${objectStoreAccessKeyId.trim()}:${objectStoreSecretKey.trim()}`,
+        });
+        setObjectStoreAccessKeyId("");
+        setObjectStoreSecretKey("");
+        setHasObjectStoreCredentials(true);
+      }
       setMessage({ type: "success", text: "設定を保存しました" });
       onSettingsChange?.();
     } catch (error) {
@@ -183,10 +793,33 @@ function Settings({ onSettingsChange }: SettingsProps) {
     }
   }
 
+  async function handleDeleteWebhookSecret() {
+    try {
+      await invoke("delete_api_key", { provider: "webhook" });
+      setHasWebhookSecret(false);
+      setMessage({ type: "success", text: "Webhookのシークレットを削除しました" });
+    } catch (error) {
+      setMessage({ type: "error", text: `削除に失敗しました: ${keychainErrorMessage(error)}` });
+    }
+  }
+
+  async function handleDeleteObjectStoreCredentials() {
+    try {
+      await invoke("delete_api_key", { provider: "objectstore" });
+      setHasObjectStoreCredentials(false);
+      setMessage({ type: "success", text: "アップロード先の認証情報を削除しました" });
+    } catch (error) {
+      setMessage({ type: "error", text: `削除に失敗しました: ${keychainErrorMessage(error)}` });
+    }
+  }
+
   function handleResetPrompt() {
     setPrompt(DEFAULT_PROMPT);
   }
 
+  const cronError = validateCron(cronExpression);
+  const capturingIndicatorError = validateCapturingIndicator(capturingIndicator);
+
   // Group models by provider
   const modelsByProvider = AVAILABLE_MODELS.reduce(
     (acc, m) => {
@@ -197,6 +830,14 @@ function Settings({ onSettingsChange }: SettingsProps) {
     {} as Record<string, typeof AVAILABLE_MODELS>
   );
 
+  // ゲートウェイから取得したモデルのうち、おすすめ一覧にないもの
+  const otherGatewayModels = gatewayModels.filter(
+    (id) => !AVAILABLE_MODELS.some((m) => m.id === id),
+  );
+  // 保存済みのモデルがどの一覧にもない場合も選択状態を表示する
+  const isKnownModel =
+    AVAILABLE_MODELS.some((m) => m.id === model) || gatewayModels.includes(model);
+
   return (
     <div className="h-full">
       {/* メッセージ表示 */}
@@ -330,7 +971,47 @@ function Settings({ onSettingsChange }: SettingsProps) {
               >
                 システム設定を開く
               </button>
+              <button
+                type="button"
+                onClick={handleTestContextInfo}
+                className="px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 transition-colors"
+              >
+                取得テスト
+              </button>
+            </div>
+            <div className="flex items-center gap-2 mt-2">
+              <span className="text-sm text-slate-600">位置情報の記録</span>
+              <select
+                value={locationPrecision}
+                onChange={(e) => setLocationPrecision(e.target.value)}
+                className="flex-1 px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+              >
+                {LOCATION_PRECISIONS.map((p) => (
+                  <option key={p.id} value={p.id}>
+                    {p.name}
+                  </option>
+                ))}
+              </select>
             </div>
+            {contextInfo && (
+              <div className="mt-2 text-xs text-slate-600 space-y-1">
+                <p>WiFi: {contextInfo.wifi_ssid ?? "取得できません"}</p>
+                <p>
+                  位置:{" "}
+                  {contextInfo.location
+                    ? `緯度${contextInfo.location.latitude.toFixed(6)}, 経度${contextInfo.location.longitude.toFixed(6)}`
+                    : locationPermissionStatus === "authorized"
+                      ? "取得できません（位置情報のキャッシュがありません）"
+                      : "取得できません（位置情報の権限がありません）"}
+                </p>
+                <p>
+                  カーソル位置:{" "}
+                  {contextInfo.cursor
+                    ? `x=${contextInfo.cursor.x}, y=${contextInfo.cursor.y}（ディスプレイ${contextInfo.cursor.display}）`
+                    : "取得できません"}
+                </p>
+              </div>
+            )}
           </div>
 
           {/* APIキー設定 */}
@@ -350,6 +1031,21 @@ function Settings({ onSettingsChange }: SettingsProps) {
               </a>
             </p>
 
+            {keychainError && (
+              <div className="mb-2">
+                <p className="text-xs text-amber-600">{keychainError.message}</p>
+                {keychainError.code === "access_denied" && (
+                  <button
+                    type="button"
+                    onClick={loadSettings}
+                    className="mt-2 px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 transition-colors"
+                  >
+                    再試行
+                  </button>
+                )}
+              </div>
+            )}
+
             {hasApiKey ? (
               <div className="flex items-center gap-2">
                 <span className="text-sm text-slate-600">APIキー: 設定済み</span>
@@ -361,6 +1057,14 @@ function Settings({ onSettingsChange }: SettingsProps) {
                 >
                   削除
                 </button>
+                <button
+                  type="button"
+                  onClick={handleTestAnalysis}
+                  disabled={isTesting}
+                  className="px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 transition-colors disabled:opacity-50"
+                >
+                  {isTesting ? "テスト中..." : "接続テスト"}
+                </button>
               </div>
             ) : (
               <div className="flex gap-2">
@@ -385,7 +1089,17 @@ function Settings({ onSettingsChange }: SettingsProps) {
 
           {/* モデル設定 */}
           <div className="p-3 border border-slate-200 rounded-sm bg-white">
-            <h2 className="text-sm font-bold text-slate-700 mb-2">モデル</h2>
+            <div className="flex items-center justify-between mb-2">
+              <h2 className="text-sm font-bold text-slate-700">モデル</h2>
+              <button
+                type="button"
+                onClick={() => loadGatewayModels(true)}
+                disabled={isLoadingModels || !hasApiKey}
+                className="px-2 py-1 text-xs border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 disabled:opacity-50 disabled:cursor-not-allowed transition-colors"
+              >
+                {isLoadingModels ? "取得中..." : "一覧を更新"}
+              </button>
+            </div>
             <p className="text-xs text-slate-500 mb-2">
               Vision対応モデルを選択してください
             </p>
@@ -403,94 +1117,1337 @@ function Settings({ onSettingsChange }: SettingsProps) {
                   ))}
                 </optgroup>
               ))}
+              {otherGatewayModels.length > 0 && (
+                <optgroup label="その他（ゲートウェイ）">
+                  {otherGatewayModels.map((id) => (
+                    <option key={id} value={id}>
+                      {id}
+                    </option>
+                  ))}
+                </optgroup>
+              )}
+              {!isKnownModel && <option value={model}>{model}</option>}
             </select>
           </div>
 
-          {/* 自動撮影間隔設定 */}
+          {/* 画像解像度設定 */}
           <div className="p-3 border border-slate-200 rounded-sm bg-white">
             <h2 className="text-sm font-bold text-slate-700 mb-2">
-              自動撮影間隔
+              分析時の画像解像度
             </h2>
             <p className="text-xs text-slate-500 mb-2">
-              自動撮影時のスクリーンショット撮影間隔（秒）
+              OpenAI系モデルのdetail指定です。低解像度にするとコストを抑えられます
             </p>
-            <div className="flex items-center gap-2">
-              <input
-                type="number"
-                min={10}
-                max={3600}
-                value={autoCaptureInterval}
-                onChange={(e) => setAutoCaptureInterval(Math.max(10, Math.min(3600, parseInt(e.target.value) || 60)))}
-                className="w-24 px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
-              />
-              <span className="text-sm text-slate-600">秒</span>
-              <span className="text-xs text-slate-500">（10〜3600秒）</span>
-            </div>
+            <select
+              value={imageDetail}
+              onChange={(e) => setImageDetail(e.target.value)}
+              className="w-full px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+            >
+              {IMAGE_DETAILS.map((d) => (
+                <option key={d.id} value={d.id}>
+                  {d.name}
+                </option>
+              ))}
+            </select>
           </div>
 
-          {/* 自動AI分析設定 */}
+          {/* 一括分析の同時実行数設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <h2 className="text-sm font-bold text-slate-700 mb-2">
+              一括分析の同時実行数
+            </h2>
+            <p className="text-xs text-slate-500 mb-2">
+              日付フォルダの再分析を並行して行います。レート制限を受けた場合は自動的にペースを落とします
+            </p>
+            <select
+              value={batchConcurrency}
+              onChange={(e) => setBatchConcurrency(Number(e.target.value))}
+              className="w-full px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+            >
+              {BATCH_CONCURRENCIES.map((c) => (
+                <option key={c.count} value={c.count}>
+                  {c.name}
+                </option>
+              ))}
+            </select>
+          </div>
+
+          {/* 回答言語設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <h2 className="text-sm font-bold text-slate-700 mb-2">
+              回答言語
+            </h2>
+            <p className="text-xs text-slate-500 mb-2">
+              AIに回答の言語を指示します（例: 「必ず日本語で回答してください」）。指定した言語は分析結果JSONに記録されます
+            </p>
+            <select
+              value={outputLanguage}
+              onChange={(e) => setOutputLanguage(e.target.value)}
+              className="w-full px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+            >
+              {OUTPUT_LANGUAGES.map((lang) => (
+                <option key={lang.id} value={lang.id}>
+                  {lang.name}
+                </option>
+              ))}
+            </select>
+          </div>
+
+          {/* OCR設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <h2 className="text-sm font-bold text-slate-700 mb-2">
+              画面の文字の読み取り（OCR）
+            </h2>
+            <p className="text-xs text-slate-500 mb-2">
+              macOSの文字認識で画面のテキストを読み取り、プロンプトに含めます（端末内で処理。読み取れない場合は画像で分析）
+            </p>
+            <select
+              value={ocrMode}
+              onChange={(e) => setOcrMode(e.target.value)}
+              className="w-full px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+            >
+              {OCR_MODES.map((mode) => (
+                <option key={mode.id} value={mode.id}>
+                  {mode.name}
+                </option>
+              ))}
+            </select>
+          </div>
+
+          {/* タスク・決定事項の抽出設定 */}
           <div className="p-3 border border-slate-200 rounded-sm bg-white">
             <div className="flex items-center justify-between">
               <div>
                 <h2 className="text-sm font-bold text-slate-700">
-                  自動AI分析
+                  タスク・決定事項を抽出
                 </h2>
                 <p className="text-xs text-slate-500 mt-1">
-                  撮影後に自動でAI分析を実行し、JSONに保存します
+                  分析のたびに、画面に写っているタスクと決定事項をJSONで抽出して分析結果に保存します（APIの呼び出しが1回増えます）
                 </p>
               </div>
               <button
                 type="button"
-                onClick={() => setAutoAnalyze(!autoAnalyze)}
+                onClick={() => setTaskExtractionEnabled(!taskExtractionEnabled)}
                 className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors ${
-                  autoAnalyze ? "bg-slate-600" : "bg-slate-300"
+                  taskExtractionEnabled ? "bg-slate-600" : "bg-slate-300"
                 }`}
               >
                 <span
                   className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform ${
-                    autoAnalyze ? "translate-x-6" : "translate-x-1"
+                    taskExtractionEnabled ? "translate-x-6" : "translate-x-1"
                   }`}
                 />
               </button>
             </div>
-            {!hasApiKey && autoAnalyze && (
-              <p className="text-xs text-amber-600 mt-2">
-                APIキーが未設定です。AI分析を行うにはAPIキーを設定してください。
-              </p>
-            )}
           </div>
 
-          {/* 保存ボタン */}
-          <button
-            type="button"
-            onClick={handleSaveSettings}
-            disabled={isSaving}
-            className="w-full px-4 py-2.5 text-sm border border-slate-400 rounded-sm bg-slate-600 hover:bg-slate-700 active:bg-slate-800 text-white font-bold transition-colors disabled:opacity-50"
-          >
-            設定を保存
-          </button>
-        </div>
+          {/* 送信する画像の上限設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <h2 className="text-sm font-bold text-slate-700 mb-2">
+              送信する画像の上限
+            </h2>
+            <p className="text-xs text-slate-500 mb-2">
+              超えた画像は縮小してから送信します。0の場合はモデルに合わせて自動（Anthropicは5MB、その他は20MB）
+            </p>
+            <div className="flex items-center gap-2">
+              <input
+                type="number"
+                min={0}
+                max={100}
+                value={maxImageSizeMb}
+                onChange={(e) => setMaxImageSizeMb(Math.max(0, Math.min(100, parseInt(e.target.value) || DEFAULT_MAX_IMAGE_SIZE_MB)))}
+                className="w-24 px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+              />
+              <span className="text-sm text-slate-600">MB</span>
+            </div>
+          </div>
 
-        {/* 右カラム */}
-        <div className="space-y-4">
-          {/* プロンプト設定 */}
-          <div className="p-3 border border-slate-200 rounded-sm bg-white h-full">
-            <div className="flex items-center justify-between mb-2">
-              <h2 className="text-sm font-bold text-slate-700">プロンプト</h2>
-              <button
-                type="button"
-                onClick={handleResetPrompt}
-                className="px-2 py-1 text-xs border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-600 transition-colors"
+          {/* 自動撮影間隔設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <h2 className="text-sm font-bold text-slate-700 mb-2">
+              自動撮影間隔
+            </h2>
+            <p className="text-xs text-slate-500 mb-2">
+              自動撮影時のスクリーンショット撮影間隔（秒）
+            </p>
+            <div className="flex items-center gap-2">
+              <input
+                type="number"
+                min={MIN_AUTO_CAPTURE_INTERVAL}
+                max={MAX_AUTO_CAPTURE_INTERVAL}
+                value={autoCaptureInterval}
+                onChange={(e) => setAutoCaptureInterval(Math.max(MIN_AUTO_CAPTURE_INTERVAL, Math.min(MAX_AUTO_CAPTURE_INTERVAL, parseInt(e.target.value) || DEFAULT_AUTO_CAPTURE_INTERVAL)))}
+                className="w-24 px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+              />
+              <span className="text-sm text-slate-600">秒</span>
+              <span className="text-xs text-slate-500">（{MIN_AUTO_CAPTURE_INTERVAL}〜{MAX_AUTO_CAPTURE_INTERVAL}秒、撮影中でも保存するとすぐに反映）</span>
+            </div>
+            <div className="flex items-center gap-2 mt-3">
+              <select
+                value={captureMode}
+                onChange={(e) => setCaptureMode(e.target.value)}
+                className="px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
               >
-                リセット
-              </button>
+                <option value="interval">一定間隔で撮影</option>
+                <option value="cron">cron式の時刻に撮影</option>
+              </select>
+              <input
+                type="text"
+                value={cronExpression}
+                onChange={(e) => setCronExpression(e.target.value)}
+                disabled={captureMode !== "cron"}
+                placeholder={DEFAULT_CRON_EXPRESSION}
+                className="flex-1 px-3 py-1.5 text-sm font-mono border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400 disabled:opacity-50"
+              />
             </div>
-            <textarea
-              value={prompt}
-              onChange={(e) => setPrompt(e.target.value)}
-              rows={10}
-              className="w-full px-3 py-2 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400 resize-none"
-            />
+            <p className="text-xs text-slate-500 mt-1">
+              {captureMode === "cron" && cronError
+                ? cronError
+                : "「分 時 日 月 曜日」の形式（例: 0,30 * * * * で毎時0分と30分、45 9 * * 1-5 で平日9:45）"}
+            </p>
+          </div>
+
+          {/* 撮影前の予告設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <h2 className="text-sm font-bold text-slate-700 mb-2">
+              撮影前の予告
+            </h2>
+            <p className="text-xs text-slate-500 mb-2">
+              自動撮影の数秒前にメニューバーへ「まもなく撮影」と表示します（0で無効）
+            </p>
+            <div className="flex items-center gap-2">
+              <input
+                type="number"
+                min={0}
+                max={MAX_PRE_CAPTURE_WARNING_SECONDS}
+                value={preCaptureWarningSeconds}
+                onChange={(e) => setPreCaptureWarningSeconds(Math.max(0, Math.min(MAX_PRE_CAPTURE_WARNING_SECONDS, parseInt(e.target.value) || 0)))}
+                className="w-24 px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+              />
+              <span className="text-sm text-slate-600">秒前</span>
+            </div>
+          </div>
+
+          {/* 1日の撮影枚数の上限設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <h2 className="text-sm font-bold text-slate-700 mb-2">
+              1日の撮影枚数の上限
+            </h2>
+            <p className="text-xs text-slate-500 mb-2">
+              今日の自動撮影の枚数が上限に達したら、日付が変わるまで自動撮影をスキップします（0で無制限、手動の撮影・インポートした画像は数えず制限もしません）
+            </p>
+            <div className="flex items-center gap-2">
+              <input
+                type="number"
+                min={0}
+                max={MAX_DAILY_CAPTURE_LIMIT}
+                value={dailyCaptureLimit}
+                onChange={(e) => setDailyCaptureLimit(Math.max(0, Math.min(MAX_DAILY_CAPTURE_LIMIT, parseInt(e.target.value) || 0)))}
+                className="w-24 px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+              />
+              <span className="text-sm text-slate-600">枚</span>
+            </div>
+          </div>
+
+          {/* バッテリー駆動時の撮影間隔設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <h2 className="text-sm font-bold text-slate-700 mb-2">
+              バッテリー駆動時の撮影間隔
+            </h2>
+            <p className="text-xs text-slate-500 mb-2">
+              バッテリー駆動中は撮影間隔をこの倍率で延長し、電力と通信量を抑えます（1で延長しない、cron・ブースト中は対象外）
+            </p>
+            <div className="flex items-center gap-2">
+              <input
+                type="number"
+                min={1}
+                max={MAX_BATTERY_INTERVAL_MULTIPLIER}
+                step={0.5}
+                value={batteryIntervalMultiplier}
+                onChange={(e) => setBatteryIntervalMultiplier(Math.max(1, Math.min(MAX_BATTERY_INTERVAL_MULTIPLIER, parseFloat(e.target.value) || 1)))}
+                className="w-24 px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+              />
+              <span className="text-sm text-slate-600">倍</span>
+            </div>
+          </div>
+
+          {/* メニューバーの残り時間の更新間隔設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <h2 className="text-sm font-bold text-slate-700 mb-2">
+              メニューバーの残り時間の更新間隔
+            </h2>
+            <p className="text-xs text-slate-500 mb-2">
+              間隔を長くすると、表示のちらつきとバッテリー消費を抑えられます（カウントダウン自体は1秒ごと）
+            </p>
+            <select
+              value={trayTitleCadence}
+              onChange={(e) => setTrayTitleCadence(Number(e.target.value))}
+              className="w-full px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+            >
+              {TRAY_TITLE_CADENCES.map((cadence) => (
+                <option key={cadence.seconds} value={cadence.seconds}>
+                  {cadence.name}
+                </option>
+              ))}
+            </select>
+          </div>
+
+          {/* 撮影中の表示設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <h2 className="text-sm font-bold text-slate-700 mb-2">
+              撮影中の表示
+            </h2>
+            <input
+              type="text"
+              value={capturingIndicator}
+              onChange={(e) => setCapturingIndicator(e.target.value)}
+              placeholder={DEFAULT_CAPTURING_INDICATOR}
+              className="w-full px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+            />
+            <p className="text-xs text-slate-500 mt-1">
+              {capturingIndicatorError ??
+                "撮影中にメニューバーに表示する文字です。絵文字がうまく表示されない場合は「撮影中」などの文字に変えてください"}
+            </p>
+          </div>
+
+          {/* 発表・画面共有中の撮影スキップ設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <div className="flex items-center justify-between">
+              <div>
+                <h2 className="text-sm font-bold text-slate-700">
+                  発表・画面共有中は撮影しない
+                </h2>
+                <p className="text-xs text-slate-500 mt-1">
+                  Zoomの画面共有やKeynote・PowerPointのスライドショー中は自動撮影をスキップします
+                </p>
+              </div>
+              <button
+                type="button"
+                onClick={() => setPauseWhilePresenting(!pauseWhilePresenting)}
+                className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors ${
+                  pauseWhilePresenting ? "bg-slate-600" : "bg-slate-300"
+                }`}
+              >
+                <span
+                  className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform ${
+                    pauseWhilePresenting ? "translate-x-6" : "translate-x-1"
+                  }`}
+                />
+              </button>
+            </div>
+          </div>
+
+          {/* 画像サイズ設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <div className="flex items-center justify-between mb-2">
+              <div>
+                <h2 className="text-sm font-bold text-slate-700">
+                  元の解像度で保存（Retina）
+                </h2>
+                <p className="text-xs text-slate-500 mt-1">
+                  縮小せずに保存し文字をくっきり残します（ファイルサイズは数倍になります）
+                </p>
+              </div>
+              <button
+                type="button"
+                onClick={() => setKeepFullResolution(!keepFullResolution)}
+                className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors ${
+                  keepFullResolution ? "bg-slate-600" : "bg-slate-300"
+                }`}
+              >
+                <span
+                  className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform ${
+                    keepFullResolution ? "translate-x-6" : "translate-x-1"
+                  }`}
+                />
+              </button>
+            </div>
+            <div className="flex items-center gap-2">
+              <span className="text-sm text-slate-600">最大幅</span>
+              <input
+                type="number"
+                min={640}
+                max={7680}
+                value={resizeMaxWidth}
+                onChange={(e) => setResizeMaxWidth(Math.max(640, Math.min(7680, parseInt(e.target.value) || DEFAULT_RESIZE_MAX_WIDTH)))}
+                disabled={keepFullResolution}
+                className="w-24 px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400 disabled:opacity-50"
+              />
+              <span className="text-sm text-slate-600">px</span>
+              <span className="text-xs text-slate-500">（これより大きい画像を縮小）</span>
+            </div>
+            <div className="flex items-center gap-2 mt-2">
+              <span className="text-sm text-slate-600">縮小方式</span>
+              <select
+                value={resizeFilter}
+                onChange={(e) => setResizeFilter(e.target.value)}
+                disabled={keepFullResolution}
+                className="flex-1 px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400 disabled:opacity-50"
+              >
+                {RESIZE_FILTERS.map((f) => (
+                  <option key={f.id} value={f.id}>
+                    {f.name}
+                  </option>
+                ))}
+              </select>
+            </div>
+            <div className="flex items-center justify-between mt-3 mb-2">
+              <div>
+                <h3 className="text-sm font-bold text-slate-700">
+                  縮小後のシャープ化
+                </h3>
+                <p className="text-xs text-slate-500 mt-1">
+                  小さな文字の輪郭を強調し、AIが読み取りやすくします
+                </p>
+              </div>
+              <button
+                type="button"
+                onClick={() => setSharpenEnabled(!sharpenEnabled)}
+                className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors ${
+                  sharpenEnabled ? "bg-slate-600" : "bg-slate-300"
+                }`}
+              >
+                <span
+                  className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform ${
+                    sharpenEnabled ? "translate-x-6" : "translate-x-1"
+                  }`}
+                />
+              </button>
+            </div>
+            <div className="flex items-center gap-2">
+              <span className="text-sm text-slate-600">強さ</span>
+              <input
+                type="number"
+                min={0.1}
+                max={5}
+                step={0.1}
+                value={sharpenSigma}
+                onChange={(e) => setSharpenSigma(Math.max(0.1, Math.min(5, parseFloat(e.target.value) || DEFAULT_SHARPEN_SIGMA)))}
+                disabled={!sharpenEnabled}
+                className="w-20 px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400 disabled:opacity-50"
+              />
+              <span className="text-sm text-slate-600">しきい値</span>
+              <input
+                type="number"
+                min={0}
+                max={255}
+                value={sharpenThreshold}
+                onChange={(e) => setSharpenThreshold(Math.max(0, Math.min(255, parseInt(e.target.value) || 0)))}
+                disabled={!sharpenEnabled}
+                className="w-20 px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400 disabled:opacity-50"
+              />
+            </div>
+            <div className="flex items-center justify-between mt-3 mb-2">
+              <div>
+                <h3 className="text-sm font-bold text-slate-700">
+                  ファイルサイズを揃える
+                </h3>
+                <p className="text-xs text-slate-500 mt-1">
+                  目標サイズに収まるようJPEG品質を自動で調整します（オフの場合は品質80）
+                </p>
+              </div>
+              <button
+                type="button"
+                onClick={() => setJpegTargetSizeEnabled(!jpegTargetSizeEnabled)}
+                className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors ${
+                  jpegTargetSizeEnabled ? "bg-slate-600" : "bg-slate-300"
+                }`}
+              >
+                <span
+                  className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform ${
+                    jpegTargetSizeEnabled ? "translate-x-6" : "translate-x-1"
+                  }`}
+                />
+              </button>
+            </div>
+            <div className="flex items-center gap-2">
+              <span className="text-sm text-slate-600">目標サイズ</span>
+              <input
+                type="number"
+                min={50}
+                max={5000}
+                step={50}
+                value={jpegTargetSizeKb}
+                onChange={(e) => setJpegTargetSizeKb(Math.max(50, Math.min(5000, parseInt(e.target.value) || DEFAULT_JPEG_TARGET_SIZE_KB)))}
+                disabled={!jpegTargetSizeEnabled}
+                className="w-24 px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400 disabled:opacity-50"
+              />
+              <span className="text-sm text-slate-600">KB</span>
+            </div>
+          </div>
+
+          {/* 画像への説明の埋め込み設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <div className="flex items-center justify-between">
+              <div>
+                <h2 className="text-sm font-bold text-slate-700">
+                  画像に説明を埋め込む
+                </h2>
+                <p className="text-xs text-slate-500 mt-1">
+                  分析結果の最初の1行をJPEGのEXIF（ImageDescription）に書き込み、Finderの「説明」やSpotlightで検索できるようにします（全文は分析結果JSONに保存）
+                </p>
+              </div>
+              <button
+                type="button"
+                onClick={() => setEmbedImageDescription(!embedImageDescription)}
+                className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors ${
+                  embedImageDescription ? "bg-slate-600" : "bg-slate-300"
+                }`}
+              >
+                <span
+                  className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform ${
+                    embedImageDescription ? "translate-x-6" : "translate-x-1"
+                  }`}
+                />
+              </button>
+            </div>
+          </div>
+
+          {/* 分析に使ったモデル・プロンプトの埋め込み設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <div className="flex items-center justify-between">
+              <div>
+                <h2 className="text-sm font-bold text-slate-700">
+                  分析したモデルを画像に記録する
+                </h2>
+                <p className="text-xs text-slate-500 mt-1">
+                  分析に使ったモデル名とプロンプトの識別子（SHA-256の先頭12桁）をJPEGのコメント（COMセグメント）に書き込み、分析結果JSONがなくてもどの設定で分析したか確認できるようにします（再圧縮しても引き継ぎます）
+                </p>
+              </div>
+              <button
+                type="button"
+                onClick={() => setEmbedAnalysisComment(!embedAnalysisComment)}
+                className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors ${
+                  embedAnalysisComment ? "bg-slate-600" : "bg-slate-300"
+                }`}
+              >
+                <span
+                  className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform ${
+                    embedAnalysisComment ? "translate-x-6" : "translate-x-1"
+                  }`}
+                />
+              </button>
+            </div>
+          </div>
+
+          {/* プログレッシブJPEG設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <div className="flex items-center justify-between">
+              <div>
+                <h2 className="text-sm font-bold text-slate-700">
+                  プログレッシブJPEGで保存
+                </h2>
+                <p className="text-xs text-slate-500 mt-1">
+                  読み込みながら徐々に鮮明に表示される形式で保存します（オフの場合はベースライン）
+                </p>
+              </div>
+              <button
+                type="button"
+                onClick={() => setProgressiveJpeg(!progressiveJpeg)}
+                className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors ${
+                  progressiveJpeg ? "bg-slate-600" : "bg-slate-300"
+                }`}
+              >
+                <span
+                  className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform ${
+                    progressiveJpeg ? "translate-x-6" : "translate-x-1"
+                  }`}
+                />
+              </button>
+            </div>
+          </div>
+
+          {/* HEIC形式での保存設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <div className="flex items-center justify-between">
+              <div>
+                <h2 className="text-sm font-bold text-slate-700">
+                  HEICで保存
+                </h2>
+                <p className="text-xs text-slate-500 mt-1">
+                  JPEGより小さいHEIC形式で保存します（macOSのみ。AI分析ではJPEGに変換して送信し、変換に失敗した場合はJPEGで保存）
+                </p>
+              </div>
+              <button
+                type="button"
+                onClick={() => setHeicOutput(!heicOutput)}
+                className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors ${
+                  heicOutput ? "bg-slate-600" : "bg-slate-300"
+                }`}
+              >
+                <span
+                  className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform ${
+                    heicOutput ? "translate-x-6" : "translate-x-1"
+                  }`}
+                />
+              </button>
+            </div>
+          </div>
+
+          {/* 保存フォルダの構成 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <h2 className="text-sm font-bold text-slate-700">
+              保存フォルダの分け方
+            </h2>
+            <p className="text-xs text-slate-500 mt-1">
+              撮影枚数が多い場合に日付フォルダ内をサブフォルダに分けます（セッション外の撮影は日付フォルダに直接保存）
+            </p>
+            <div className="flex items-center gap-2 mt-2">
+              <span className="text-sm text-slate-600">サブフォルダ</span>
+              <select
+                value={subfolderScheme}
+                onChange={(e) => setSubfolderScheme(e.target.value)}
+                className="flex-1 px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+              >
+                {SUBFOLDER_SCHEMES.map((scheme) => (
+                  <option key={scheme.id} value={scheme.id}>
+                    {scheme.name}
+                  </option>
+                ))}
+              </select>
+            </div>
+            <div className="flex items-center gap-2 mt-2">
+              <span className="text-sm text-slate-600">連番の桁数</span>
+              <select
+                value={sequencePadding}
+                onChange={(e) => setSequencePadding(Number(e.target.value))}
+                className="flex-1 px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+              >
+                {SEQUENCE_PADDINGS.map((padding) => (
+                  <option key={padding.width} value={padding.width}>
+                    {padding.name}
+                  </option>
+                ))}
+              </select>
+            </div>
+          </div>
+
+          {/* 保存する画像の暗号化設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <div className="flex items-center justify-between">
+              <div>
+                <h2 className="text-sm font-bold text-slate-700">
+                  保存する画像を暗号化
+                </h2>
+                <p className="text-xs text-slate-500 mt-1">
+                  キーチェーンに保存したキーで画像を暗号化して保存します（.jpg.enc）。表示・分析時はメモリ上で復号します。HEIC変換・元画像と一時ファイルの保存・撮影ごとのPDF追記は行いません
+                </p>
+              </div>
+              <button
+                type="button"
+                onClick={() => setEncryptAtRest(!encryptAtRest)}
+                className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors ${
+                  encryptAtRest ? "bg-slate-600" : "bg-slate-300"
+                }`}
+              >
+                <span
+                  className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform ${
+                    encryptAtRest ? "translate-x-6" : "translate-x-1"
+                  }`}
+                />
+              </button>
+            </div>
+          </div>
+
+          {/* 元画像の保存設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <div className="flex items-center justify-between mb-2">
+              <div>
+                <h2 className="text-sm font-bold text-slate-700">
+                  元画像を残す
+                </h2>
+                <p className="text-xs text-slate-500 mt-1">
+                  縮小前の画像を日付フォルダのoriginalsに同じファイル名で保存します
+                </p>
+              </div>
+              <button
+                type="button"
+                onClick={() => setKeepOriginals(!keepOriginals)}
+                className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors ${
+                  keepOriginals ? "bg-slate-600" : "bg-slate-300"
+                }`}
+              >
+                <span
+                  className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform ${
+                    keepOriginals ? "translate-x-6" : "translate-x-1"
+                  }`}
+                />
+              </button>
+            </div>
+            <div className="flex items-center gap-2">
+              <input
+                type="number"
+                min={0}
+                max={3650}
+                value={pruneOriginalsDays}
+                onChange={(e) => setPruneOriginalsDays(Math.max(0, Math.min(3650, parseInt(e.target.value) || 0)))}
+                className="w-20 px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+              />
+              <span className="text-sm text-slate-600">日より前の元画像を</span>
+              <button
+                type="button"
+                onClick={handlePruneOriginals}
+                disabled={isPruningOriginals}
+                className="px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 transition-colors disabled:opacity-50"
+              >
+                {isPruningOriginals ? "削除中..." : "削除"}
+              </button>
+            </div>
+          </div>
+
+          {/* 撮影時の一時ファイル設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <div className="flex items-center justify-between">
+              <div>
+                <h2 className="text-sm font-bold text-slate-700">
+                  撮影時の一時ファイルを残す
+                </h2>
+                <p className="text-xs text-slate-500 mt-1">
+                  不具合の調査用に、縮小前の一時ファイルを削除せずに残します（有効な間は起動時の掃除も行わないため、調査が終わったら無効にしてください）
+                </p>
+              </div>
+              <button
+                type="button"
+                onClick={() => setKeepSourceFiles(!keepSourceFiles)}
+                className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors ${
+                  keepSourceFiles ? "bg-slate-600" : "bg-slate-300"
+                }`}
+              >
+                <span
+                  className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform ${
+                    keepSourceFiles ? "translate-x-6" : "translate-x-1"
+                  }`}
+                />
+              </button>
+            </div>
+          </div>
+
+          {/* バックグラウンドでの実行継続設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <div className="flex items-center justify-between">
+              <div>
+                <h2 className="text-sm font-bold text-slate-700">
+                  閉じてもバックグラウンドで実行
+                </h2>
+                <p className="text-xs text-slate-500 mt-1">
+                  ウィンドウを閉じたりCommand+Qを押しても終了せず、メニューバーで動き続けます（オフにすると通常のアプリと同じく終了）
+                </p>
+              </div>
+              <button
+                type="button"
+                onClick={() => setKeepRunningInBackground(!keepRunningInBackground)}
+                className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors ${
+                  keepRunningInBackground ? "bg-slate-600" : "bg-slate-300"
+                }`}
+              >
+                <span
+                  className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform ${
+                    keepRunningInBackground ? "translate-x-6" : "translate-x-1"
+                  }`}
+                />
+              </button>
+            </div>
+          </div>
+
+          {/* トレーアイコン設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <h2 className="text-sm font-bold text-slate-700 mb-2">
+              トレーアイコン
+            </h2>
+            <p className="text-xs text-slate-500 mb-2">
+              メニューバーのアイコンを変更します（PNG、黒と透明で作るとライト・ダークに合わせて表示されます）
+            </p>
+            {(
+              [
+                { variant: "idle", label: "待機中" },
+                { variant: "running", label: "自動撮影中" },
+              ] as const
+            ).map(({ variant, label }) => (
+              <div key={variant} className="flex items-center gap-2 mb-1">
+                <span className="w-20 text-sm text-slate-600">{label}</span>
+                {trayIcons[variant] ? (
+                  <img
+                    src={`data:image/png;base64,${trayIcons[variant]}`}
+                    alt={label}
+                    className="h-5 w-5 object-contain"
+                  />
+                ) : (
+                  <span className="text-xs text-slate-400">デフォルト</span>
+                )}
+                <label className="px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 transition-colors cursor-pointer">
+                  選択
+                  <input
+                    type="file"
+                    accept="image/png"
+                    className="hidden"
+                    onChange={(e) => handleTrayIconChange(variant, e.target.files?.[0] ?? null)}
+                  />
+                </label>
+                {trayIcons[variant] && (
+                  <button
+                    type="button"
+                    onClick={() => handleTrayIconChange(variant, null)}
+                    className="px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 transition-colors"
+                  >
+                    リセット
+                  </button>
+                )}
+              </div>
+            ))}
+            <div className="flex items-center gap-2 mt-2">
+              <span className="w-20 text-sm text-slate-600">左クリック</span>
+              <select
+                value={trayLeftClick}
+                onChange={(e) => setTrayLeftClick(e.target.value)}
+                className="flex-1 px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+              >
+                {TRAY_LEFT_CLICK_BEHAVIORS.map((b) => (
+                  <option key={b.id} value={b.id}>
+                    {b.name}
+                  </option>
+                ))}
+              </select>
+            </div>
+          </div>
+
+          {/* 日時表示形式設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <h2 className="text-sm font-bold text-slate-700 mb-2">
+              日時の表示形式
+            </h2>
+            <p className="text-xs text-slate-500 mb-2">
+              分析結果JSONの表示用日時（display_time）に使用します
+            </p>
+            <select
+              value={displayDateFormat}
+              onChange={(e) => setDisplayDateFormat(e.target.value)}
+              className="w-full px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+            >
+              {DISPLAY_DATE_FORMATS.map((f) => (
+                <option key={f.id} value={f.id}>
+                  {f.name}
+                </option>
+              ))}
+            </select>
+          </div>
+
+          {/* Webhook転送設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <h2 className="text-sm font-bold text-slate-700 mb-2">
+              Webhook転送
+            </h2>
+            <p className="text-xs text-slate-500 mb-2">
+              保存したスクリーンショットをmultipart/form-dataで送信します（空欄で無効）
+            </p>
+            <input
+              type="url"
+              value={forwardWebhookUrl}
+              onChange={(e) => setForwardWebhookUrl(e.target.value)}
+              placeholder="https://example.com/webhook"
+              className="w-full px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400 mb-2"
+            />
+            {hasWebhookSecret ? (
+              <div className="flex items-center gap-2">
+                <span className="text-sm text-slate-600">シークレット: 設定済み</span>
+                <button
+                  type="button"
+                  onClick={handleDeleteWebhookSecret}
+                  className="px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 transition-colors"
+                >
+                  削除
+                </button>
+              </div>
+            ) : (
+              <input
+                type="password"
+                value={webhookSecret}
+                onChange={(e) => setWebhookSecret(e.target.value)}
+                placeholder="シークレット（任意、Bearerトークンとして送信）"
+                className="w-full px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+              />
+            )}
+          </div>
+
+          {/* 画像のアップロード先設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <h2 className="text-sm font-bold text-slate-700 mb-2">
+              画像のアップロード先
+            </h2>
+            <p className="text-xs text-slate-500 mb-2">
+              S3互換のストレージに画像をアップロードし、base64の代わりに署名付きURLを送信します。リクエストが小さくなります（空欄またはアップロード失敗時はbase64で送信）。分析後に画像は削除しますが、削除できなかった画像が残らないようバケットにライフサイクルルール（auto-daily-report/を1日で期限切れなど）を設定してください
+            </p>
+            <input
+              type="url"
+              value={imageUploadEndpoint}
+              onChange={(e) => setImageUploadEndpoint(e.target.value)}
+              placeholder="https://s3.ap-northeast-1.amazonaws.com"
+              className="w-full px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400 mb-2"
+            />
+            <div className="flex gap-2 mb-2">
+              <input
+                type="text"
+                value={imageUploadBucket}
+                onChange={(e) => setImageUploadBucket(e.target.value)}
+                placeholder="バケット名"
+                className="flex-1 px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+              />
+              <input
+                type="text"
+                value={imageUploadRegion}
+                onChange={(e) => setImageUploadRegion(e.target.value)}
+                placeholder="リージョン（既定: us-east-1）"
+                className="flex-1 px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+              />
+            </div>
+            {hasObjectStoreCredentials ? (
+              <div className="flex items-center gap-2">
+                <span className="text-sm text-slate-600">認証情報: 設定済み</span>
+                <button
+                  type="button"
+                  onClick={handleDeleteObjectStoreCredentials}
+                  className="px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-700 transition-colors"
+                >
+                  削除
+                </button>
+              </div>
+            ) : (
+              <div className="flex gap-2">
+                <input
+                  type="text"
+                  value={objectStoreAccessKeyId}
+                  onChange={(e) => setObjectStoreAccessKeyId(e.target.value)}
+                  placeholder="アクセスキーID"
+                  className="flex-1 px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+                />
+                <input
+                  type="password"
+                  value={objectStoreSecretKey}
+                  onChange={(e) => setObjectStoreSecretKey(e.target.value)}
+                  placeholder="シークレットアクセスキー"
+                  className="flex-1 px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+                />
+              </div>
+            )}
+          </div>
+
+          {/* 撮影するディスプレイ設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <h2 className="text-sm font-bold text-slate-700 mb-2">
+              撮影するディスプレイ
+            </h2>
+            <p className="text-xs text-slate-500 mb-2">
+              すべてを選ぶと、1回の撮影でディスプレイごとに連番で保存します
+            </p>
+            <select
+              value={captureDisplay}
+              onChange={(e) => setCaptureDisplay(e.target.value)}
+              className="w-full px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400"
+            >
+              <option value="main">メインディスプレイ</option>
+              <option value="all">すべてのディスプレイ</option>
+              {monitors.map((m) => (
+                <option key={m.id} value={String(m.id)}>
+                  {m.name}
+                </option>
+              ))}
+            </select>
+          </div>
+
+          {/* 撮影範囲設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <div className="flex items-center justify-between mb-2">
+              <div>
+                <h2 className="text-sm font-bold text-slate-700">
+                  撮影範囲
+                </h2>
+                <p className="text-xs text-slate-500 mt-1">
+                  画面の一部だけを切り抜いて保存します（範囲が画面に収まらない場合は画面全体）
+                </p>
+              </div>
+              <button
+                type="button"
+                onClick={() => setCaptureRegionEnabled(!captureRegionEnabled)}
+                className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors ${
+                  captureRegionEnabled ? "bg-slate-600" : "bg-slate-300"
+                }`}
+              >
+                <span
+                  className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform ${
+                    captureRegionEnabled ? "translate-x-6" : "translate-x-1"
+                  }`}
+                />
+              </button>
+            </div>
+            <div className="grid grid-cols-4 gap-2">
+              {(["x", "y", "width", "height"] as const).map((key) => (
+                <label key={key} className="flex flex-col gap-1">
+                  <span className="text-xs text-slate-500">{key}</span>
+                  <input
+                    type="number"
+                    min={key === "width" || key === "height" ? 1 : 0}
+                    value={captureRegion[key]}
+                    onChange={(e) =>
+                      setCaptureRegion({
+                        ...captureRegion,
+                        [key]: Math.max(key === "width" || key === "height" ? 1 : 0, parseInt(e.target.value) || 0),
+                      })
+                    }
+                    disabled={!captureRegionEnabled}
+                    className="w-full px-2 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400 disabled:opacity-50"
+                  />
+                </label>
+              ))}
+            </div>
+            <p className="text-xs text-slate-500 mt-2">
+              座標は実ピクセル（Retinaでは表示上の2倍）
+            </p>
+          </div>
+
+          {/* 撮影時間帯設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <div className="flex items-center justify-between mb-2">
+              <div>
+                <h2 className="text-sm font-bold text-slate-700">
+                  撮影時間帯
+                </h2>
+                <p className="text-xs text-slate-500 mt-1">
+                  時間帯に入ると自動撮影を開始し、終わると停止します
+                </p>
+              </div>
+              <button
+                type="button"
+                onClick={() => setScheduleEnabled(!scheduleEnabled)}
+                className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors ${
+                  scheduleEnabled ? "bg-slate-600" : "bg-slate-300"
+                }`}
+              >
+                <span
+                  className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform ${
+                    scheduleEnabled ? "translate-x-6" : "translate-x-1"
+                  }`}
+                />
+              </button>
+            </div>
+            <div className="flex items-center gap-2">
+              <input
+                type="time"
+                value={scheduleStart}
+                onChange={(e) => setScheduleStart(e.target.value)}
+                disabled={!scheduleEnabled}
+                className="px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400 disabled:opacity-50"
+              />
+              <span className="text-sm text-slate-600">〜</span>
+              <input
+                type="time"
+                value={scheduleEnd}
+                onChange={(e) => setScheduleEnd(e.target.value)}
+                disabled={!scheduleEnabled}
+                className="px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400 disabled:opacity-50"
+              />
+            </div>
+          </div>
+
+          {/* カウントダウンを表示しない時間帯設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <div className="flex items-center justify-between mb-2">
+              <div>
+                <h2 className="text-sm font-bold text-slate-700">
+                  カウントダウンを表示しない時間帯
+                </h2>
+                <p className="text-xs text-slate-500 mt-1">
+                  時間帯の間はメニューバーの残り時間を消します（撮影は続けます）
+                </p>
+              </div>
+              <button
+                type="button"
+                onClick={() => setQuietHoursEnabled(!quietHoursEnabled)}
+                className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors ${
+                  quietHoursEnabled ? "bg-slate-600" : "bg-slate-300"
+                }`}
+              >
+                <span
+                  className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform ${
+                    quietHoursEnabled ? "translate-x-6" : "translate-x-1"
+                  }`}
+                />
+              </button>
+            </div>
+            <div className="flex items-center gap-2">
+              <input
+                type="time"
+                value={quietHoursStart}
+                onChange={(e) => setQuietHoursStart(e.target.value)}
+                disabled={!quietHoursEnabled}
+                className="px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400 disabled:opacity-50"
+              />
+              <span className="text-sm text-slate-600">〜</span>
+              <input
+                type="time"
+                value={quietHoursEnd}
+                onChange={(e) => setQuietHoursEnd(e.target.value)}
+                disabled={!quietHoursEnabled}
+                className="px-3 py-1.5 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400 disabled:opacity-50"
+              />
+            </div>
+          </div>
+
+          {/* 自動AI分析設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <div className="flex items-center justify-between">
+              <div>
+                <h2 className="text-sm font-bold text-slate-700">
+                  自動AI分析
+                </h2>
+                <p className="text-xs text-slate-500 mt-1">
+                  撮影後に自動でAI分析を実行し、JSONに保存します
+                </p>
+              </div>
+              <button
+                type="button"
+                onClick={() => setAutoAnalyze(!autoAnalyze)}
+                className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors ${
+                  autoAnalyze ? "bg-slate-600" : "bg-slate-300"
+                }`}
+              >
+                <span
+                  className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform ${
+                    autoAnalyze ? "translate-x-6" : "translate-x-1"
+                  }`}
+                />
+              </button>
+            </div>
+            {!hasApiKey && autoAnalyze && (
+              <p className="text-xs text-amber-600 mt-2">
+                APIキーが未設定です。AI分析を行うにはAPIキーを設定してください。
+              </p>
+            )}
+          </div>
+
+          {/* 撮影後に分析しない（後でまとめて分析）設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <div className="flex items-center justify-between">
+              <div>
+                <h2 className="text-sm font-bold text-slate-700">
+                  分析を後でまとめて行う
+                </h2>
+                <p className="text-xs text-slate-500 mt-1">
+                  撮影時はコンテキスト情報のみ保存し、AI分析は一括分析の「未分析のみ」で実行します（自動AI分析より優先）
+                </p>
+              </div>
+              <button
+                type="button"
+                onClick={() => setOnDemandAnalysis(!onDemandAnalysis)}
+                className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors ${
+                  onDemandAnalysis ? "bg-slate-600" : "bg-slate-300"
+                }`}
+              >
+                <span
+                  className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform ${
+                    onDemandAnalysis ? "translate-x-6" : "translate-x-1"
+                  }`}
+                />
+              </button>
+            </div>
+          </div>
+
+          {/* 変化が少ない画面の簡易記録設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <div className="flex items-center justify-between">
+              <div>
+                <h2 className="text-sm font-bold text-slate-700">
+                  変化が少ない画面の簡易記録
+                </h2>
+                <p className="text-xs text-slate-500 mt-1">
+                  自動撮影で同じフォルダの前回の撮影とほぼ同じ画面はAIを呼ばずに「前回とほぼ同じ画面」と記録します（手動・一括の分析では比較しません）
+                </p>
+              </div>
+              <button
+                type="button"
+                onClick={() => setIdleNoteEnabled(!idleNoteEnabled)}
+                className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors ${
+                  idleNoteEnabled ? "bg-slate-600" : "bg-slate-300"
+                }`}
+              >
+                <span
+                  className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform ${
+                    idleNoteEnabled ? "translate-x-6" : "translate-x-1"
+                  }`}
+                />
+              </button>
+            </div>
+          </div>
+
+          {/* 分析しなかった撮影のコンテキスト記録設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <div className="flex items-center justify-between">
+              <div>
+                <h2 className="text-sm font-bold text-slate-700">
+                  分析しなかった撮影も場所を記録
+                </h2>
+                <p className="text-xs text-slate-500 mt-1">
+                  分析前・分析に失敗した撮影にもWiFi・位置情報と日時だけのJSONを保存し、1日の記録を途切れさせません
+                </p>
+              </div>
+              <button
+                type="button"
+                onClick={() => setContextSnapshotEnabled(!contextSnapshotEnabled)}
+                className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors ${
+                  contextSnapshotEnabled ? "bg-slate-600" : "bg-slate-300"
+                }`}
+              >
+                <span
+                  className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform ${
+                    contextSnapshotEnabled ? "translate-x-6" : "translate-x-1"
+                  }`}
+                />
+              </button>
+            </div>
+          </div>
+
+          {/* 分析ごとの日報更新設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <div className="flex items-center justify-between">
+              <div>
+                <h2 className="text-sm font-bold text-slate-700">
+                  日報を自動で更新
+                </h2>
+                <p className="text-xs text-slate-500 mt-1">
+                  分析するたびに日付フォルダのreport.mdへ新しい分析結果を追記します
+                </p>
+              </div>
+              <button
+                type="button"
+                onClick={() => setLiveReportEnabled(!liveReportEnabled)}
+                className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors ${
+                  liveReportEnabled ? "bg-slate-600" : "bg-slate-300"
+                }`}
+              >
+                <span
+                  className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform ${
+                    liveReportEnabled ? "translate-x-6" : "translate-x-1"
+                  }`}
+                />
+              </button>
+            </div>
+          </div>
+
+          {/* 撮影ごとのPDF追記設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white">
+            <div className="flex items-center justify-between">
+              <div>
+                <h2 className="text-sm font-bold text-slate-700">
+                  撮影ごとにPDFへ追記
+                </h2>
+                <p className="text-xs text-slate-500 mt-1">
+                  撮影のたびに日付フォルダのcaptures.pdfへ1ページ追加します（前のページは書き直さないため、開いたままのPDFにページが増えていきます）
+                </p>
+              </div>
+              <button
+                type="button"
+                onClick={() => setLivePdfEnabled(!livePdfEnabled)}
+                className={`relative inline-flex h-6 w-11 items-center rounded-full transition-colors ${
+                  livePdfEnabled ? "bg-slate-600" : "bg-slate-300"
+                }`}
+              >
+                <span
+                  className={`inline-block h-4 w-4 transform rounded-full bg-white transition-transform ${
+                    livePdfEnabled ? "translate-x-6" : "translate-x-1"
+                  }`}
+                />
+              </button>
+            </div>
+          </div>
+
+          {/* 保存ボタン */}
+          <button
+            type="button"
+            onClick={handleSaveSettings}
+            disabled={isSaving}
+            className="w-full px-4 py-2.5 text-sm border border-slate-400 rounded-sm bg-slate-600 hover:bg-slate-700 active:bg-slate-800 text-white font-bold transition-colors disabled:opacity-50"
+          >
+            設定を保存
+          </button>
+        </div>
+
+        {/* 右カラム */}
+        <div className="space-y-4">
+          {/* プロンプト設定 */}
+          <div className="p-3 border border-slate-200 rounded-sm bg-white h-full">
+            <div className="flex items-center justify-between mb-2">
+              <h2 className="text-sm font-bold text-slate-700">プロンプト</h2>
+              <div className="flex items-center gap-2">
+                <button
+                  type="button"
+                  onClick={handlePreviewPrompt}
+                  className="px-2 py-1 text-xs border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-600 transition-colors"
+                >
+                  プレビュー
+                </button>
+                <button
+                  type="button"
+                  onClick={handleResetPrompt}
+                  className="px-2 py-1 text-xs border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-600 transition-colors"
+                >
+                  リセット
+                </button>
+              </div>
+            </div>
+            <textarea
+              value={prompt}
+              onChange={(e) => setPrompt(e.target.value)}
+              rows={10}
+              className="w-full px-3 py-2 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400 resize-none"
+            />
+
+            {/* 共通ヘッダー・フッター */}
+            <h2 className="text-sm font-bold text-slate-700 mt-3 mb-1">
+              共通ヘッダー
+            </h2>
+            <p className="text-xs text-slate-500 mb-2">
+              すべてのプロンプトの前に追加されます（例: 回答の形式やペルソナ）
+            </p>
+            <textarea
+              value={promptPrefix}
+              onChange={(e) => setPromptPrefix(e.target.value)}
+              rows={3}
+              className="w-full px-3 py-2 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400 resize-none"
+            />
+            <h2 className="text-sm font-bold text-slate-700 mt-3 mb-1">
+              共通フッター
+            </h2>
+            <p className="text-xs text-slate-500 mb-2">
+              プロンプトと追加コンテキストの後に追加されます
+            </p>
+            <textarea
+              value={promptSuffix}
+              onChange={(e) => setPromptSuffix(e.target.value)}
+              rows={3}
+              className="w-full px-3 py-2 text-sm border border-slate-300 rounded-sm bg-white focus:outline-none focus:border-slate-400 resize-none"
+            />
+
+            {/* 送信されるプロンプトのプレビュー */}
+            {promptPreview !== null && (
+              <>
+                <div className="flex items-center justify-between mt-3 mb-1">
+                  <h2 className="text-sm font-bold text-slate-700">
+                    送信されるプロンプト（{promptPreview.length}文字）
+                  </h2>
+                  <button
+                    type="button"
+                    onClick={() => setPromptPreview(null)}
+                    className="px-2 py-1 text-xs border border-slate-300 rounded-sm bg-white hover:bg-slate-100 active:bg-slate-200 text-slate-600 transition-colors"
+                  >
+                    閉じる
+                  </button>
+                </div>
+                <p className="text-xs text-slate-500 mb-2">
+                  ヘッダー・フッターは保存済みの設定を使用します
+                </p>
+                <pre className="w-full px-3 py-2 text-xs border border-slate-200 rounded-sm bg-slate-50 text-slate-700 whitespace-pre-wrap">
+                  {promptPreview}
+                </pre>
+              </>
+            )}
           </div>
         </div>
       </div>
@@ -500,4 +2457,22 @@ function Settings({ onSettingsChange }: SettingsProps) {
 
 export default Settings;
 
-export { DEFAULT_MODEL, DEFAULT_PROMPT, DEFAULT_AUTO_CAPTURE_INTERVAL, DEFAULT_AUTO_ANALYZE };
+export {
+  DEFAULT_MODEL,
+  DEFAULT_PROMPT,
+  DEFAULT_AUTO_CAPTURE_INTERVAL,
+  DEFAULT_CAPTURE_MODE,
+  MIN_AUTO_CAPTURE_INTERVAL,
+  MAX_AUTO_CAPTURE_INTERVAL,
+  DEFAULT_PRE_CAPTURE_WARNING_SECONDS,
+  DEFAULT_TRAY_TITLE_CADENCE,
+  DEFAULT_CAPTURING_INDICATOR,
+  validateCapturingIndicator,
+  DEFAULT_AUTO_ANALYZE,
+  DEFAULT_SCHEDULE_ENABLED,
+  DEFAULT_SCHEDULE_START,
+  DEFAULT_SCHEDULE_END,
+  DEFAULT_QUIET_HOURS_ENABLED,
+  DEFAULT_QUIET_HOURS_START,
+  DEFAULT_QUIET_HOURS_END,
+};
@@ -0,0 +1,134 @@
+// cron形式（分 時 日 月 曜日の5フィールド）の撮影スケジュール
+// 各フィールドは * / 数値 / 範囲（a-b）/ 間隔（*/n, a-b/n）/ カンマ区切りのリストに対応
+// 曜日は0〜7（0と7は日曜）。日と曜日の両方を指定した場合は、どちらかに一致すれば撮影する（標準のcronと同じ）
+
+export interface CronSchedule {
+  minutes: Set<number>;
+  hours: Set<number>;
+  daysOfMonth: Set<number>;
+  months: Set<number>;
+  daysOfWeek: Set<number>;
+  // 日・曜日が*以外で指定されているか（両方指定時のOR判定用）
+  dayOfMonthRestricted: boolean;
+  dayOfWeekRestricted: boolean;
+}
+
+// 次回撮影時刻を探す上限（これより先に一致する時刻がなければnull）
+const MAX_SEARCH_DAYS = 366 * 4;
+
+// 1フィールドを解析して一致する値の集合を返す（不正な場合はエラー）
+function parseField(field: string, min: number, max: number): Set<number> {
+  const values = new Set<number>();
+  for (const part of field.split(",")) {
+    const [range, stepText] = part.split("/");
+    const step = stepText === undefined ? 1 : Number(stepText);
+    if (!Number.isInteger(step) || step < 1) {
+      throw new Error(`間隔の指定が不正です: ${part}`);
+    }
+
+    let start: number;
+    let end: number;
+    if (range === "*") {
+      start = min;
+      end = max;
+    } else if (range.includes("-")) {
+      const [a, b] = range.split("-");
+      start = Number(a);
+      end = Number(b);
+    } else {
+      start = Number(range);
+      // 「5/15」は5から最大値まで15ごと
+      end = stepText === undefined ? start : max;
+    }
+
+    if (
+      range === "" ||
+      !Number.isInteger(start) ||
+      !Number.isInteger(end) ||
+      start < min ||
+      end > max ||
+      start > end
+    ) {
+      throw new Error(`範囲外の値です: ${part}（${min}〜${max}）`);
+    }
+    for (let v = start; v <= end; v += step) {
+      values.add(v);
+    }
+  }
+  return values;
+}
+
+// cron式を解析する（不正な場合はエラーメッセージ付きの例外）
+export function parseCron(expression: string): CronSchedule {
+  const fields = expression.trim().split(/\s+/);
+  if (fields.length !== 5) {
+    throw new Error("cron式は「分 時 日 月 曜日」の5項目で指定してください");
+  }
+  const [minute, hour, dayOfMonth, month, dayOfWeek] = fields;
+
+  // 曜日の7は日曜（0）として扱う
+  const daysOfWeek = new Set(
+    [...parseField(dayOfWeek, 0, 7)].map((d) => (d === 7 ? 0 : d)),
+  );
+
+  return {
+    minutes: parseField(minute, 0, 59),
+    hours: parseField(hour, 0, 23),
+    daysOfMonth: parseField(dayOfMonth, 1, 31),
+    months: parseField(month, 1, 12),
+    daysOfWeek,
+    dayOfMonthRestricted: dayOfMonth !== "*",
+    dayOfWeekRestricted: dayOfWeek !== "*",
+  };
+}
+
+// cron式が正しければnull、不正ならエラーメッセージを返す（設定画面の入力チェック用）
+export function validateCron(expression: string): string | null {
+  try {
+    parseCron(expression);
+    return null;
+  } catch (error) {
+    return error instanceof Error ? error.message : String(error);
+  }
+}
+
+function matchesDay(schedule: CronSchedule, date: Date): boolean {
+  const domMatch = schedule.daysOfMonth.has(date.getDate());
+  const dowMatch = schedule.daysOfWeek.has(date.getDay());
+  if (schedule.dayOfMonthRestricted && schedule.dayOfWeekRestricted) {
+    return domMatch || dowMatch;
+  }
+  return domMatch && dowMatch;
+}
+
+// afterより後（分単位、afterと同じ分は含まない）で最初に一致する時刻を返す
+export function nextCronTime(schedule: CronSchedule, after: Date): Date | null {
+  const time = new Date(after.getTime());
+  time.setSeconds(0, 0);
+  time.setMinutes(time.getMinutes() + 1);
+
+  const limit = after.getTime() + MAX_SEARCH_DAYS * 24 * 60 * 60 * 1000;
+  while (time.getTime() <= limit) {
+    if (!schedule.months.has(time.getMonth() + 1)) {
+      // 翌月の1日0時へ
+      time.setMonth(time.getMonth() + 1, 1);
+      time.setHours(0, 0, 0, 0);
+      continue;
+    }
+    if (!matchesDay(schedule, time)) {
+      time.setDate(time.getDate() + 1);
+      time.setHours(0, 0, 0, 0);
+      continue;
+    }
+    if (!schedule.hours.has(time.getHours())) {
+      time.setHours(time.getHours() + 1, 0, 0, 0);
+      continue;
+    }
+    if (!schedule.minutes.has(time.getMinutes())) {
+      time.setMinutes(time.getMinutes() + 1, 0, 0);
+      continue;
+    }
+    return time;
+  }
+  return null;
+}